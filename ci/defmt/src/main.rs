@@ -0,0 +1,32 @@
+//! Checks that `Unused` can be logged with `defmt` on an embedded target.
+//!
+//! This is only compiled, not run:
+//!
+//! ```sh
+//! cargo check --manifest-path ci/defmt/Cargo.toml --target thumbv7em-none-eabi
+//! ```
+
+#![no_std]
+#![no_main]
+
+use core::panic::PanicInfo;
+
+use unused::Unused;
+
+#[derive(defmt::Format)]
+struct Reading {
+    value: u32,
+    unused: Unused!(u32),
+}
+
+#[no_mangle]
+pub fn log(value: u32) {
+    let unused: Unused!(u32) = Unused;
+    defmt::info!("{}", unused);
+    defmt::info!("{}", Reading { value, unused });
+}
+
+#[panic_handler]
+fn panic(_info: &PanicInfo<'_>) -> ! {
+    loop {}
+}
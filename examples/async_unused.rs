@@ -0,0 +1,40 @@
+//! Uses `PendingForever` to disable a branch of a `select!`.
+//!
+//! ```sh
+//! cargo run --example async_unused --features async
+//! ```
+
+use futures::executor::block_on;
+use futures::future::{self, Either, FutureExt};
+use unused::{PendingForever, Unused};
+
+/// Finishes the work, unless the optional timeout elapses first.
+///
+/// Without a timeout, the timeout branch is an `Unused`, which never
+/// completes, so `select!` always picks the work.
+async fn run(work: u32, timeout: Option<()>) -> Result<u32, &'static str> {
+    let mut timeout = match timeout {
+        Some(()) => Either::Left(future::ready(())),
+        None => {
+            let pending: PendingForever<()> = Unused;
+            Either::Right(FutureExt::map(pending, |never| match never {}))
+        }
+    }
+    .fuse();
+    let mut work = future::ready(work);
+
+    futures::select_biased! {
+        () = timeout => Err("timed out"),
+        value = work => Ok(value),
+    }
+}
+
+fn main() {
+    block_on(async {
+        assert_eq!(run(42, None).await, Ok(42));
+        println!("finished without a timeout");
+
+        assert_eq!(run(42, Some(())).await, Err("timed out"));
+        println!("timed out with a timeout that had already elapsed");
+    });
+}
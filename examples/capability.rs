@@ -0,0 +1,49 @@
+//! Requires a `Capability` to access the network or the disk.
+//!
+//! ```sh
+//! cargo run --example capability
+//! ```
+//!
+//! Only `main` creates capabilities, so reading `main` is enough to know
+//! which parts of the program can access what.
+
+use unused::Capability;
+
+/// The capability to access the network.
+enum NetworkAccess {}
+
+/// The capability to access the disk.
+enum DiskAccess {}
+
+/// Sends a request to a host, which requires network access.
+fn fetch(_network: &Capability<NetworkAccess>, host: &str) -> String {
+    format!("response from {}", host)
+}
+
+/// Saves a response, which requires disk access.
+fn save(_disk: &Capability<DiskAccess>, response: &str) -> usize {
+    response.len()
+}
+
+/// Fetches and saves a response, which requires both capabilities.
+fn download(
+    network: &Capability<NetworkAccess>,
+    disk: &Capability<DiskAccess>,
+    host: &str,
+) -> usize {
+    save(disk, &fetch(network, host))
+}
+
+fn main() {
+    // SAFETY: this program is allowed to access the network and the disk.
+    let (network, disk) = unsafe {
+        (
+            Capability::<NetworkAccess>::new(),
+            Capability::<DiskAccess>::new(),
+        )
+    };
+    assert_eq!(std::mem::size_of_val(&network), 0);
+
+    println!("{}", fetch(&network, "example.com"));
+    println!("saved {} bytes", download(&network, &disk, "example.com"));
+}
@@ -0,0 +1,41 @@
+//! Uses `Unused` to carry the types of a `frunk` `HList`.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::thread;
+
+use frunk::HList;
+use unused::{CovariantHList, HListUnused, Unused};
+
+/// A set of handlers for events, where `Events` is an `HList` of event types.
+struct Handlers<Events: HListUnused> {
+    names: Vec<&'static str>,
+    #[allow(dead_code)]
+    unused: CovariantHList<Events>,
+}
+
+impl<Events: HListUnused> Handlers<Events> {
+    fn new(names: Vec<&'static str>) -> Self {
+        Self {
+            names,
+            unused: Unused,
+        }
+    }
+}
+
+fn main() {
+    // Neither `Rc<str>` nor `Cell<u8>` is `Sync`, and `Rc<str>` is not `Send`.
+    type Events = HList![Rc<str>, Cell<u8>, u32];
+
+    let handlers: Handlers<Events> = Handlers::new(vec!["text", "counter", "number"]);
+
+    // `Handlers` is still `Send` and `Sync`, whatever the events are.
+    let handlers = thread::spawn(move || handlers).join().unwrap();
+    println!("{} handlers", handlers.names.len());
+
+    assert_eq!(
+        std::mem::size_of::<CovariantHList<Events>>(),
+        0,
+        "the types take up no space",
+    );
+}
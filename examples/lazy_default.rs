@@ -0,0 +1,157 @@
+//! Extends the `LazyFromStr` example from the README into a
+//! `LazyTypeDefault`, which can lazily create a `T` with either its
+//! `FromStr` or its `Default` impl.
+//!
+//! `LazyTypeDefault` never owns a `T`, so it uses `Unused!(T: covariant)`
+//! instead of `PhantomData<T>`. This makes it `Send` and `Sync` for every
+//! `T`, so it can be sent to other threads and stored in `static`s, even if
+//! `T` is not `Send` or `Sync`.
+
+use std::convert::Infallible;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::thread;
+
+use unused::Unused;
+
+/// Lazily creates a `T`, from a string if there is one, and with its
+/// `Default` impl otherwise.
+struct LazyTypeDefault<T> {
+    str: Option<&'static str>,
+    // `T` is only ever created, like the return type of a function, so it
+    // is covariant.
+    #[allow(dead_code)]
+    unused: Unused!(T: covariant),
+}
+
+impl<T> LazyTypeDefault<T> {
+    /// Creates a `T` with `FromStr`.
+    const fn parse(str: &'static str) -> Self {
+        Self {
+            str: Some(str),
+            unused: Unused,
+        }
+    }
+
+    /// Creates a `T` with `Default`.
+    const fn default() -> Self {
+        Self {
+            str: None,
+            unused: Unused,
+        }
+    }
+}
+
+impl<T: Default> LazyTypeDefault<T> {
+    /// Creates a `T` with `Default`, ignoring the string.
+    fn create_default(&self) -> T {
+        T::default()
+    }
+}
+
+impl<T: Default + FromStr> LazyTypeDefault<T> {
+    /// Creates a `T` from the string, or with `Default` if there is no
+    /// string or it cannot be parsed.
+    fn create(&self) -> T {
+        match self.str {
+            Some(str) => str.parse().unwrap_or_default(),
+            None => self.create_default(),
+        }
+    }
+}
+
+/// `RcString` is neither `Send` nor `Sync`.
+#[derive(Debug, Default, PartialEq)]
+struct RcString(Rc<String>);
+
+impl FromStr for RcString {
+    type Err = Infallible;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Ok(Self(Rc::new(str.to_owned())))
+    }
+}
+
+/// A `static` must be `Sync`, which `LazyTypeDefault<RcString>` is.
+static GREETING: LazyTypeDefault<RcString> = LazyTypeDefault::parse("hello");
+
+/// `OnceLock<T>` is `Sync` if `T` is `Send` and `Sync`, so a
+/// `LazyTypeDefault` can also be chosen at runtime and shared from a
+/// `static`.
+static NAME: OnceLock<LazyTypeDefault<RcString>> = OnceLock::new();
+
+/// Sends `LazyTypeDefault<RcString>`s to other threads, and shares them
+/// from `static`s.
+fn threads() {
+    // With `PhantomData<T>`, this would not compile, as
+    // `LazyTypeDefault<RcString>` would be neither `Send` nor `Sync`:
+    //
+    // struct LazyTypeDefault<T> {
+    //     str: Option<&'static str>,
+    //     phantom: PhantomData<T>,
+    // }
+    //
+    // // error[E0277]: `Rc<String>` cannot be sent between threads safely
+    // thread::spawn(move || lazy.create()).join().unwrap();
+
+    let lazy: LazyTypeDefault<RcString> = LazyTypeDefault::parse("a");
+    let empty: LazyTypeDefault<RcString> = LazyTypeDefault::default();
+
+    // Each `RcString` is created and dropped on the thread that uses it.
+    thread::spawn(move || {
+        assert_eq!(*lazy.create().0, "a");
+        assert_eq!(*empty.create().0, "");
+    })
+    .join()
+    .unwrap();
+
+    NAME.get_or_init(|| match std::env::args().nth(1) {
+        Some(_) => LazyTypeDefault::parse("argument"),
+        None => LazyTypeDefault::default(),
+    });
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            thread::spawn(|| {
+                let greeting = GREETING.create();
+                let name = NAME.get().unwrap().create();
+                format!("{}, {:?}", greeting.0, name.0)
+            })
+        })
+        .collect();
+    for handle in handles {
+        println!("{}", handle.join().unwrap());
+    }
+}
+
+/// `LazyTypeDefault` is covariant, like `PhantomData<T>`, so a
+/// `LazyTypeDefault<&'static str>` can be used where a shorter lifetime is
+/// expected.
+fn variance() {
+    fn shorten<'a>(lazy: LazyTypeDefault<&'static str>) -> LazyTypeDefault<&'a str> {
+        lazy
+    }
+
+    fn first_or_default<'a>(strs: &[&'a str], lazy: LazyTypeDefault<&'a str>) -> &'a str {
+        strs.first()
+            .copied()
+            .unwrap_or_else(|| lazy.create_default())
+    }
+
+    let owned = String::from("owned");
+    let strs = [owned.as_str()];
+    assert_eq!(
+        first_or_default(&strs, shorten(LazyTypeDefault::default())),
+        "owned"
+    );
+    assert_eq!(
+        first_or_default(&[], shorten(LazyTypeDefault::default())),
+        ""
+    );
+}
+
+fn main() {
+    threads();
+    variance();
+}
@@ -0,0 +1,24 @@
+//! Returns `Unused` from `main`, which always exits successfully.
+//!
+//! ```sh
+//! cargo run --example main_returns_unused --features std
+//! ```
+
+use unused::Unused;
+
+/// Runs an entry point that returns any `unused::Termination`, which is
+/// how a `no_std` binary can use `Unused` as a return type.
+fn run<T: unused::Termination>(entry: fn() -> T) -> u8 {
+    entry().report()
+}
+
+fn entry() -> Unused!(()) {
+    println!("running entry");
+    Unused
+}
+
+fn main() -> Unused!(()) {
+    assert_eq!(run(entry), 0);
+    println!("exiting successfully");
+    Unused
+}
@@ -0,0 +1,258 @@
+//! A guide to migrating from `PhantomData` to `Unused`.
+//!
+//! Each section shows a struct written with `PhantomData` ("before"), and the
+//! same struct written with `Unused` ("after"). The variance is the same, but
+//! the "after" struct is always `Send` and `Sync`, which is shown by moving it
+//! to another thread.
+//!
+//! During a migration, each `PhantomData` can be converted to the matching
+//! `Unused` with `unused::from_phantom_variance`, and back with
+//! `unused::into_phantom_variance`.
+
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::thread;
+
+use unused::Unused;
+
+/// A type that is neither `Send` nor `Sync`, to show that `Unused` does not
+/// depend on the auto traits of its types.
+type Local = Rc<Cell<u8>>;
+
+/// Moves a value to another thread and back.
+fn round_trip<T: Send + 'static>(value: T) -> T {
+    thread::spawn(move || value).join().unwrap()
+}
+
+/// (1) `PhantomData<T>` becomes `Unused!(T: covariant)`.
+///
+/// `PhantomData<T>` is covariant, so a `Reader<&'static str>` can be used
+/// as a `Reader<&'a str>`. It also acts as if it owns a `T`, so
+/// `before::Reader<Local>` cannot be sent to another thread.
+mod covariant {
+    use super::*;
+
+    pub mod before {
+        use super::*;
+
+        pub struct Reader<T> {
+            pub position: usize,
+            pub phantom: PhantomData<T>,
+        }
+
+        pub fn shorten<'a>(reader: Reader<&'static str>) -> Reader<&'a str> {
+            reader
+        }
+    }
+
+    pub mod after {
+        use super::*;
+
+        pub struct Reader<T> {
+            pub position: usize,
+            #[allow(dead_code)]
+            pub unused: Unused!(T: covariant),
+        }
+
+        pub fn shorten<'a>(reader: Reader<&'static str>) -> Reader<&'a str> {
+            reader
+        }
+    }
+
+    pub fn run() {
+        let before = before::shorten(before::Reader {
+            position: 1,
+            phantom: PhantomData,
+        });
+
+        // The `PhantomData` converts directly into the matching `Unused`.
+        let after = after::shorten(after::Reader {
+            position: before.position,
+            unused: unused::from_phantom_variance(before.phantom),
+        });
+        assert_eq!(after.position, 1);
+
+        // `before::Reader<Local>` is not `Send`, but `after::Reader<Local>` is.
+        let local: after::Reader<Local> = after::Reader {
+            position: 2,
+            unused: Unused,
+        };
+        assert_eq!(round_trip(local).position, 2);
+    }
+}
+
+/// (2) `PhantomData<fn(T) -> T>` becomes `Unused!(T)`.
+///
+/// `T` is both an argument and a return type of `fn(T) -> T`, so it is
+/// invariant. `Unused!(T)` is invariant by default. Function pointers are
+/// always `Send` and `Sync`, so both structs can be sent to another thread.
+mod invariant {
+    use super::*;
+
+    pub mod before {
+        use super::*;
+
+        pub struct Slot<T> {
+            pub index: usize,
+            pub phantom: PhantomData<fn(T) -> T>,
+        }
+    }
+
+    pub mod after {
+        use super::*;
+
+        pub struct Slot<T> {
+            pub index: usize,
+            #[allow(dead_code)]
+            pub unused: Unused!(T),
+        }
+    }
+
+    pub fn run() {
+        let before: before::Slot<Local> = before::Slot {
+            index: 3,
+            phantom: PhantomData,
+        };
+        let before = round_trip(before);
+
+        let after: after::Slot<Local> = after::Slot {
+            index: before.index,
+            unused: unused::from_phantom_variance(before.phantom),
+        };
+        assert_eq!(round_trip(after).index, 3);
+    }
+}
+
+/// (3) `PhantomData<fn(T)>` becomes `Unused!(T: contravariant)`.
+///
+/// `T` is only an argument of `fn(T)`, so it is contravariant: a
+/// `Sink<&'a str>` can be used as a `Sink<&'static str>`, for any `'a`.
+mod contravariant {
+    use super::*;
+
+    pub mod before {
+        use super::*;
+
+        pub struct Sink<T> {
+            pub count: usize,
+            pub phantom: PhantomData<fn(T)>,
+        }
+
+        pub fn lengthen(sink: Sink<&str>) -> Sink<&'static str> {
+            sink
+        }
+    }
+
+    pub mod after {
+        use super::*;
+
+        pub struct Sink<T> {
+            pub count: usize,
+            #[allow(dead_code)]
+            pub unused: Unused!(T: contravariant),
+        }
+
+        pub fn lengthen(sink: Sink<&str>) -> Sink<&'static str> {
+            sink
+        }
+    }
+
+    pub fn run() {
+        let before = before::lengthen(before::Sink {
+            count: 4,
+            phantom: PhantomData,
+        });
+
+        let after = after::lengthen(after::Sink {
+            count: before.count,
+            unused: unused::from_phantom_variance(before.phantom),
+        });
+        assert_eq!(round_trip(after).count, 4);
+    }
+}
+
+/// (4) `PhantomData<*mut T>` becomes `Unused!(T)`.
+///
+/// `*mut T` is invariant, but raw pointers are also neither `Send` nor
+/// `Sync`, so `PhantomData<*mut T>` is often written to get invariance and
+/// opt out of both auto traits at the same time. `Unused!(T)` only gives the
+/// invariance. If the struct must not be `Send` or `Sync`, say so with a
+/// separate field, such as `unused::NonSend` or `unused::NonSync`, so that
+/// each field has a single reason.
+mod raw_pointer {
+    use super::*;
+
+    pub mod before {
+        use super::*;
+
+        pub struct Handle<T> {
+            pub id: u32,
+            pub phantom: PhantomData<*mut T>,
+        }
+    }
+
+    pub mod after {
+        use super::*;
+
+        pub struct Handle<T> {
+            pub id: u32,
+            #[allow(dead_code)]
+            pub unused: Unused!(T),
+        }
+    }
+
+    pub fn run() {
+        // `before::Handle<u8>` cannot be sent to another thread, even though
+        // `u8` can.
+        let before: before::Handle<u8> = before::Handle {
+            id: 5,
+            phantom: PhantomData,
+        };
+
+        let after: after::Handle<u8> = after::Handle {
+            id: before.id,
+            unused: Unused,
+        };
+        assert_eq!(round_trip(after).id, 5);
+    }
+}
+
+/// (5) `Unused` is `Send` and `Sync` regardless of its types.
+///
+/// `PhantomData<T>` is only `Send` and `Sync` if `T` is, which leaks the
+/// auto traits of types that are never stored. `Unused` is always both, so
+/// a struct that only mentions a type is shared across threads based on the
+/// fields it actually has.
+mod auto_traits {
+    use super::*;
+
+    fn is_send_sync<T: Send + Sync>() {}
+
+    pub fn run() {
+        // Neither of these compile, as `Local` is neither `Send` nor `Sync`:
+        //
+        // is_send_sync::<PhantomData<Local>>();
+        // is_send_sync::<PhantomData<*mut u8>>();
+
+        is_send_sync::<Unused!(Local)>();
+        is_send_sync::<Unused!(*mut u8: covariant, Local: contravariant)>();
+
+        let shared: &'static Unused!(Local) = &Unused;
+        let handles: Vec<_> = (0..4)
+            .map(|_| thread::spawn(move || *shared == Unused))
+            .collect();
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+    }
+}
+
+fn main() {
+    covariant::run();
+    invariant::run();
+    contravariant::run();
+    raw_pointer::run();
+    auto_traits::run();
+    println!("all migrations behave the same, and can be sent across threads");
+}
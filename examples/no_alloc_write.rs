@@ -0,0 +1,105 @@
+//! Checks that writing to `Unused` with `write!` never allocates.
+//!
+//! The global allocator panics on any allocation made while writing. On the
+//! host, this runs as a normal binary:
+//!
+//! ```sh
+//! cargo run --example no_alloc_write
+//! ```
+//!
+//! On embedded targets, it is built as a `#![no_std]` and `#![no_main]`
+//! binary by the `ci/no_alloc` crate, since the dev-dependencies of `unused`
+//! require `std`:
+//!
+//! ```sh
+//! cargo build --manifest-path ci/no_alloc/Cargo.toml --target thumbv6m-none-eabi
+//! cargo build --manifest-path ci/no_alloc/Cargo.toml --target riscv32imc-unknown-none-elf
+//! ```
+
+#![cfg_attr(target_os = "none", no_std, no_main)]
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::fmt::Write;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use unused::Unused;
+
+/// Whether allocations are currently forbidden.
+static FORBIDDEN: AtomicBool = AtomicBool::new(false);
+
+/// An allocator which panics on any allocation while allocations are
+/// forbidden.
+///
+/// On embedded targets, there is nothing to allocate from, so it always
+/// panics.
+struct PanickingAlloc;
+
+unsafe impl GlobalAlloc for PanickingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if FORBIDDEN.load(Ordering::SeqCst) {
+            panic!(
+                "allocated {} bytes while writing to `Unused`",
+                layout.size()
+            );
+        }
+        fallback::alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        fallback::dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: PanickingAlloc = PanickingAlloc;
+
+#[cfg(not(target_os = "none"))]
+mod fallback {
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    pub unsafe fn alloc(layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    pub unsafe fn dealloc(ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[cfg(target_os = "none")]
+mod fallback {
+    use core::alloc::Layout;
+
+    pub unsafe fn alloc(layout: Layout) -> *mut u8 {
+        panic!("allocated {} bytes with no heap", layout.size());
+    }
+
+    pub unsafe fn dealloc(_ptr: *mut u8, _layout: Layout) {}
+}
+
+/// Writes formatted values of several types to an `Unused`, with allocations
+/// forbidden.
+#[no_mangle]
+pub extern "C" fn write_to_unused(value: u32) -> bool {
+    let mut unused: Unused!(u32) = Unused;
+
+    FORBIDDEN.store(true, Ordering::SeqCst);
+    let result = write!(unused, "{} {:?} {:>8} {:#x}", value, "str", 1.5, value)
+        .and_then(|()| writeln!(unused))
+        .and_then(|()| unused.write_char('c'));
+    FORBIDDEN.store(false, Ordering::SeqCst);
+
+    result.is_ok()
+}
+
+#[cfg(not(target_os = "none"))]
+fn main() {
+    assert!(write_to_unused(42));
+    println!("writing to `Unused` did not allocate");
+}
+
+#[cfg(target_os = "none")]
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo<'_>) -> ! {
+    loop {}
+}
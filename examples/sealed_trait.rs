@@ -0,0 +1,82 @@
+//! Seals a trait with `unused::sealed`, so that it cannot be implemented
+//! outside of the library that defines it.
+//!
+//! The `shapes` module stands in for a library crate.
+
+mod shapes {
+    use unused::sealed::{HasSealedToken, Sealed, SealedToken};
+
+    // Declares `Seal`, which cannot be named outside of this module.
+    unused::sealed_token!(Seal);
+
+    /// A shape, which can only be implemented by this library.
+    ///
+    /// As `Shape` is sealed, new methods can be added to it without breaking
+    /// any other crates.
+    pub trait Shape: HasSealedToken<Seal> {
+        /// The name of the shape.
+        fn name(&self) -> &'static str;
+
+        /// The number of sides of the shape.
+        fn sides(&self) -> u32 {
+            self.sides_internal(Sealed::new(Seal))
+        }
+
+        /// Can only be called by this library, as a `Sealed` cannot be created
+        /// without a `Seal`.
+        #[doc(hidden)]
+        fn sides_internal(&self, sealed: Sealed<Self, Seal>) -> u32;
+    }
+
+    pub struct Triangle;
+
+    impl SealedToken<Triangle> for Seal {}
+
+    impl Shape for Triangle {
+        fn name(&self) -> &'static str {
+            "triangle"
+        }
+
+        fn sides_internal(&self, _sealed: Sealed<Self, Seal>) -> u32 {
+            3
+        }
+    }
+
+    pub struct Square;
+
+    impl SealedToken<Square> for Seal {}
+
+    impl Shape for Square {
+        fn name(&self) -> &'static str {
+            "square"
+        }
+
+        fn sides_internal(&self, _sealed: Sealed<Self, Seal>) -> u32 {
+            4
+        }
+    }
+}
+
+use shapes::Shape;
+
+// Implementing `Shape` here would not compile, as `shapes::Seal` is private:
+//
+// struct Pentagon;
+//
+// impl unused::sealed::SealedToken<Pentagon> for shapes::Seal {}
+//
+// impl Shape for Pentagon { ... }
+//
+// Neither would implementing the seal for every token, as it is already
+// implemented for every type that a token seals:
+//
+// impl<T: ?Sized> unused::sealed::HasSealedToken<T> for Pentagon {}
+
+fn describe<S: Shape>(shape: &S) {
+    println!("a {} has {} sides", shape.name(), shape.sides());
+}
+
+fn main() {
+    describe(&shapes::Triangle);
+    describe(&shapes::Square);
+}
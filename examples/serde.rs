@@ -0,0 +1,65 @@
+//! Compares using `#[serde(skip)]` on an `Unused` field with relying on the
+//! `Serialize` and `Deserialize` implementations from the `serde` feature.
+
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+use unused::Unused;
+
+// Derives cannot be used on generic structs with macros in field types, so we
+// use a type alias. `#[serde(bound = "")]` is used below so that `T` does not
+// need to implement `Serialize` or `Deserialize`.
+type UnusedT<T> = Unused!(T);
+
+/// The `Unused` field is skipped, so it does not appear in the output.
+///
+/// Deserializing fills the field in with `Default::default`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+struct Skipped<T> {
+    value: u8,
+    #[serde(skip)]
+    #[allow(dead_code)]
+    unused: UnusedT<T>,
+}
+
+/// The `Unused` field is serialized as `null`.
+///
+/// Deserializing accepts `null`, `{}`, or a missing field.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+struct Serialized<T> {
+    value: u8,
+    unused: UnusedT<T>,
+}
+
+fn main() {
+    let skipped: Skipped<Rc<str>> = Skipped {
+        value: 1,
+        unused: Unused,
+    };
+    let json = serde_json::to_string(&skipped).unwrap();
+    println!("skipped: {}", json);
+    println!(
+        "skipped, deserialized: {:?}",
+        serde_json::from_str::<Skipped<Rc<str>>>(&json).unwrap(),
+    );
+
+    let serialized: Serialized<Rc<str>> = Serialized {
+        value: 1,
+        unused: Unused,
+    };
+    let json = serde_json::to_string(&serialized).unwrap();
+    println!("serialized: {}", json);
+    for json in [
+        json.as_str(),
+        r#"{"value":1,"unused":{}}"#,
+        r#"{"value":1}"#,
+    ] {
+        println!(
+            "serialized, deserialized from {}: {:?}",
+            json,
+            serde_json::from_str::<Serialized<Rc<str>>>(json).unwrap(),
+        );
+    }
+}
@@ -0,0 +1,84 @@
+//! Uses `Unused` to implement a type-state machine.
+//!
+//! The state is only known at the type level, so the machine never owns or
+//! allocates a `State`.
+
+use std::marker::PhantomData;
+use std::thread;
+
+use unused::Unused;
+
+/// The initial state.
+struct Start;
+
+/// The state after [`start`].
+struct Running;
+
+/// The state after [`finish`].
+struct Done;
+
+/// A state that is neither `Send` nor `Sync`.
+#[allow(dead_code)]
+struct Local(PhantomData<*const ()>);
+
+struct StateMachine<State> {
+    steps: u32,
+    #[allow(dead_code)]
+    unused: Unused!(State: covariant),
+}
+
+impl StateMachine<Start> {
+    fn new() -> Self {
+        Self {
+            steps: 0,
+            unused: Unused,
+        }
+    }
+}
+
+/// Only a machine in the `Start` state can be started.
+fn start(machine: StateMachine<Start>) -> StateMachine<Running> {
+    StateMachine {
+        steps: machine.steps + 1,
+        unused: Unused,
+    }
+}
+
+/// Only a machine in the `Running` state can be finished.
+fn finish(machine: StateMachine<Running>) -> StateMachine<Done> {
+    StateMachine {
+        steps: machine.steps + 1,
+        unused: Unused,
+    }
+}
+
+/// The same machine using `PhantomData`, which acts like it owns a `State`.
+#[allow(dead_code)]
+struct PhantomStateMachine<State> {
+    steps: u32,
+    phantom: PhantomData<State>,
+}
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+fn main() {
+    let machine = start(StateMachine::new());
+    // Moving the machine to another thread requires it to be `Send`.
+    let machine = thread::spawn(move || finish(machine)).join().unwrap();
+    println!("done after {} steps", machine.steps);
+
+    // `StateMachine` is always `Send` and `Sync`, whatever its state is.
+    assert_send_sync::<StateMachine<Start>>();
+    assert_send_sync::<StateMachine<Local>>();
+
+    // `PhantomStateMachine` is only `Send` and `Sync` if its state is, so this
+    // does not compile:
+    // assert_send_sync::<PhantomStateMachine<Local>>();
+    assert_send_sync::<PhantomStateMachine<Start>>();
+
+    // Both are the same size, as the state takes up no space.
+    assert_eq!(
+        std::mem::size_of::<StateMachine<Start>>(),
+        std::mem::size_of::<PhantomStateMachine<Start>>(),
+    );
+}
@@ -0,0 +1,64 @@
+//! Uses `Unused` to carry type-level numbers from `typenum`.
+//!
+//! Any type can be used with `Unused`. The `typenum` feature adds
+//! `UnusedTypenumN`, which converts a const generic into a `typenum` number.
+
+use std::marker::PhantomData;
+use std::ops::Add;
+use std::rc::Rc;
+use std::thread;
+
+use typenum::{Sum, Unsigned, U2, U3, U5};
+use unused::{Unused, UnusedTypenumN};
+
+/// A buffer of bytes with a length of `N`, which is only known at the type
+/// level.
+///
+/// `T` is the type that the bytes represent.
+struct Buffer<N, T> {
+    bytes: Vec<u8>,
+    #[allow(dead_code)]
+    unused: Unused!(N: covariant, T),
+}
+
+impl<N: Unsigned, T> Buffer<N, T> {
+    fn zeroed() -> Self {
+        Self {
+            bytes: vec![0; N::USIZE],
+            unused: Unused,
+        }
+    }
+
+    fn concat<M: Unsigned>(self, other: Buffer<M, T>) -> Buffer<Sum<N, M>, T>
+    where
+        N: Add<M>,
+    {
+        let mut bytes = self.bytes;
+        bytes.extend(other.bytes);
+        Buffer {
+            bytes,
+            unused: Unused,
+        }
+    }
+}
+
+fn main() {
+    let a: Buffer<U2, Rc<str>> = Buffer::zeroed();
+    let b: Buffer<U3, Rc<str>> = Buffer::zeroed();
+
+    let c: Buffer<U5, Rc<str>> = a.concat(b);
+    assert_eq!(c.bytes.len(), U5::USIZE);
+
+    // `Buffer` is `Send`, even though `Rc<str>` is not.
+    let c = thread::spawn(move || c).join().unwrap();
+    println!("{} bytes", c.bytes.len());
+
+    // A const generic value can be turned into a `typenum` number.
+    let five: UnusedTypenumN<5> = unused::typenum_n::<5>();
+    let five: Unused!(U5: covariant) = five;
+    assert_eq!(five.typenum_value(), c.bytes.len());
+
+    // `Unused!(U5: covariant)` is zero-sized, just like `PhantomData<U5>`.
+    assert_eq!(std::mem::size_of::<Unused!(U5: covariant)>(), 0);
+    assert_eq!(std::mem::size_of::<PhantomData<U5>>(), 0);
+}
@@ -0,0 +1,59 @@
+//! Exports a struct with an `Unused` field to JavaScript using `wasm-bindgen`.
+//!
+//! Build with:
+//!
+//! ```sh
+//! cargo build --example wasm_unused --features wasm-bindgen --target wasm32-unknown-unknown
+//! ```
+
+use std::rc::Rc;
+
+use unused::Unused;
+use wasm_bindgen::prelude::*;
+
+type UnusedRc = Unused!(Rc<str>: covariant);
+
+/// A counter, which is tagged with an unused `Rc<str>`.
+#[wasm_bindgen]
+pub struct Counter {
+    pub count: u32,
+    pub unused: UnusedRc,
+}
+
+#[wasm_bindgen]
+impl Counter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Counter {
+        Counter {
+            count: 0,
+            unused: Unused,
+        }
+    }
+
+    pub fn increment(&mut self) {
+        self.count += 1;
+    }
+}
+
+impl Default for Counter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns an `Unused`, which is `undefined` in JavaScript.
+#[wasm_bindgen]
+pub fn make_unused() -> UnusedRc {
+    Unused
+}
+
+/// Accepts an `Unused`, which can be passed as `undefined` from JavaScript.
+#[wasm_bindgen]
+pub fn take_unused(_unused: UnusedRc) {}
+
+fn main() {
+    let mut counter = Counter::new();
+    counter.increment();
+    take_unused(make_unused());
+    println!("count: {}", counter.count);
+}
@@ -0,0 +1,22 @@
+use core::alloc::{AllocError, Allocator, Layout};
+use core::ptr::NonNull;
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// `Unused` is an allocator that can never allocate, so a collection that uses
+/// it can never hold any data.
+///
+/// `deallocate` is never called, as nothing is ever allocated.
+//
+// SAFETY: `allocate` never returns a block of memory, so there are no blocks
+// that could be invalidated.
+unsafe impl<T: UnusedInner> Allocator for UnusedImpl<T> {
+    #[inline]
+    fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Err(AllocError)
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+}
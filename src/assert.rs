@@ -0,0 +1,66 @@
+/// Asserts at compile time that a type is zero-sized.
+///
+/// Every [`Unused`](type@crate::Unused) is zero-sized:
+///
+/// ```
+/// use unused::{assert_zero_sized, Unused};
+///
+/// assert_zero_sized!(Unused!(u8));
+/// assert_zero_sized!(Unused!(String: covariant, [u8; 1024]: contravariant));
+/// ```
+///
+/// Other types cause a compilation error:
+///
+/// ```compile_fail
+/// use unused::assert_zero_sized;
+///
+/// struct Large([u8; 1024]);
+///
+/// assert_zero_sized!(Large);
+/// ```
+#[macro_export]
+macro_rules! assert_zero_sized {
+    ($type:ty $(,)?) => {
+        const _: () = {
+            if ::core::mem::size_of::<$type>() != 0 {
+                ::core::panic!(::core::concat!(
+                    "`",
+                    ::core::stringify!($type),
+                    "` is not zero-sized",
+                ));
+            }
+        };
+    };
+}
+
+/// Asserts at compile time that a type has an alignment of 1.
+///
+/// Every [`Unused`](type@crate::Unused) has an alignment of 1:
+///
+/// ```
+/// use unused::{assert_align_one, Unused};
+///
+/// assert_align_one!(Unused!(u64));
+/// ```
+///
+/// Other types cause a compilation error:
+///
+/// ```compile_fail
+/// use unused::assert_align_one;
+///
+/// assert_align_one!(u64);
+/// ```
+#[macro_export]
+macro_rules! assert_align_one {
+    ($type:ty $(,)?) => {
+        const _: () = {
+            if ::core::mem::align_of::<$type>() != 1 {
+                ::core::panic!(::core::concat!(
+                    "`",
+                    ::core::stringify!($type),
+                    "` does not have an alignment of 1",
+                ));
+            }
+        };
+    };
+}
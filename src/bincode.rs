@@ -0,0 +1,29 @@
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// `Unused` is encoded as zero bytes.
+impl<T: UnusedInner> Encode for UnusedImpl<T> {
+    fn encode<E: Encoder>(&self, _encoder: &mut E) -> Result<(), EncodeError> {
+        Ok(())
+    }
+}
+
+/// Decoding an `Unused` reads zero bytes.
+impl<T: UnusedInner, Context> Decode<Context> for UnusedImpl<T> {
+    fn decode<D: Decoder<Context = Context>>(_decoder: &mut D) -> Result<Self, DecodeError> {
+        Ok(Self::Unused)
+    }
+}
+
+impl<'de, T: UnusedInner, Context> BorrowDecode<'de, Context> for UnusedImpl<T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        _decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self::Unused)
+    }
+}
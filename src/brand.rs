@@ -0,0 +1,147 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::inner::UnusedInner;
+use crate::invariant::Invariant;
+use crate::unused::UnusedImpl;
+
+/// A zero-sized brand, which is invariant over an identifying type `Id`, as
+/// well as the types in `T`.
+///
+/// Brands with different `Id`s are different types, so values branded in one
+/// context cannot be mixed up with values branded in another. A unique `Id`
+/// can be created with [`brand_token!`](crate::brand_token!).
+///
+/// ```
+/// use unused::{Brand, End};
+///
+/// enum IdA {}
+/// enum IdB {}
+///
+/// struct Index<Id> {
+///     index: usize,
+///     brand: Brand<End, Id>,
+/// }
+///
+/// fn get<Id>(values: &[u8], _brand: Brand<End, Id>, index: Index<Id>) -> u8 {
+///     values[index.index]
+/// }
+///
+/// let brand_a: Brand<End, IdA> = Brand::new();
+/// let index = Index { index: 1, brand: brand_a };
+/// assert_eq!(get(&[1, 2, 3], brand_a, index), 2);
+/// ```
+///
+/// Using an `Index<IdA>` with a `Brand<End, IdB>` does not compile:
+///
+/// ```compile_fail
+/// # use unused::{Brand, End};
+/// # enum IdA {}
+/// # enum IdB {}
+/// # struct Index<Id> {
+/// #     index: usize,
+/// #     brand: Brand<End, Id>,
+/// # }
+/// # fn get<Id>(values: &[u8], _brand: Brand<End, Id>, index: Index<Id>) -> u8 {
+/// #     values[index.index]
+/// # }
+/// let brand_a: Brand<End, IdA> = Brand::new();
+/// let brand_b: Brand<End, IdB> = Brand::new();
+/// let index = Index { index: 1, brand: brand_a };
+/// get(&[1, 2, 3], brand_b, index);
+/// ```
+pub struct Brand<T: UnusedInner, Id: ?Sized> {
+    unused: UnusedImpl<Invariant<Id, T>>,
+}
+
+impl<T: UnusedInner, Id: ?Sized> Brand<T, Id> {
+    /// Creates a new `Brand`.
+    pub const fn new() -> Self {
+        Self {
+            unused: UnusedImpl::Unused,
+        }
+    }
+
+    /// Converts this `Brand` into the `Unused` that it contains.
+    pub const fn into_unused(self) -> UnusedImpl<Invariant<Id, T>> {
+        self.unused
+    }
+}
+
+impl<T: UnusedInner, Id: ?Sized> Clone for Brand<T, Id> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: UnusedInner, Id: ?Sized> Copy for Brand<T, Id> {}
+
+impl<T: UnusedInner, Id: ?Sized> fmt::Debug for Brand<T, Id> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Brand").finish()
+    }
+}
+
+impl<T: UnusedInner, Id: ?Sized> Default for Brand<T, Id> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: UnusedInner, Id: ?Sized> PartialEq for Brand<T, Id> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T: UnusedInner, Id: ?Sized> Eq for Brand<T, Id> {}
+
+impl<T: UnusedInner, Id: ?Sized> PartialOrd for Brand<T, Id> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: UnusedInner, Id: ?Sized> Ord for Brand<T, Id> {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<T: UnusedInner, Id: ?Sized> Hash for Brand<T, Id> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Calls a closure with a [`Brand`] whose `Id` is a new type, local to the
+/// invocation of this macro.
+///
+/// Every invocation of `brand_token!` creates a different `Id`, so brands
+/// from different invocations can never be mixed up.
+///
+/// ```
+/// use unused::{brand_token, Brand, End};
+///
+/// fn check<Id>(_a: Brand<End, Id>, _b: Brand<End, Id>) {}
+///
+/// brand_token!(|brand| check(brand, brand));
+/// ```
+///
+/// ```compile_fail
+/// use unused::{brand_token, Brand, End};
+///
+/// fn check<Id>(_a: Brand<End, Id>, _b: Brand<End, Id>) {}
+///
+/// brand_token!(|a| brand_token!(|b| check(a, b)));
+/// ```
+///
+/// Note that the `Id` is created once for each place where `brand_token!` is
+/// written, so calling it repeatedly, such as in a loop, gives brands with
+/// the same `Id`.
+#[macro_export]
+macro_rules! brand_token {
+    ($closure:expr) => {{
+        enum __BrandId {}
+        ($closure)($crate::Brand::<$crate::End, __BrandId>::new())
+    }};
+}
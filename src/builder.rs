@@ -0,0 +1,96 @@
+use core::fmt;
+
+use crate::contravariant::Contravariant;
+use crate::covariant::Covariant;
+use crate::end::End;
+use crate::inner::UnusedInner;
+use crate::invariant::Invariant;
+use crate::list::{PushBack, UnusedConcat};
+use crate::unused::UnusedImpl;
+
+/// A builder for [`Unused`](type@crate::Unused) types, for contexts where the
+/// [`Unused!`](crate::Unused!) macro cannot easily be used, such as generated
+/// code.
+///
+/// Each method appends a type with a variance, in the same order as the
+/// arguments to `Unused!`:
+///
+/// ```
+/// use unused::{Unused, UnusedBuilder};
+///
+/// let unused: Unused!(u8, u16: covariant, u32: contravariant) = UnusedBuilder::new()
+///     .invariant::<u8>()
+///     .covariant::<u16>()
+///     .contravariant::<u32>()
+///     .build();
+/// ```
+pub struct UnusedBuilder<T: UnusedInner = End> {
+    unused: UnusedImpl<T>,
+}
+
+impl UnusedBuilder {
+    /// Creates a new `UnusedBuilder`, with no types.
+    pub const fn new() -> Self {
+        Self {
+            unused: UnusedImpl::Unused,
+        }
+    }
+}
+
+impl<T: UnusedInner> UnusedBuilder<T> {
+    /// Appends a type that is invariant, like `A: invariant` in `Unused!`.
+    pub const fn invariant<A: ?Sized>(self) -> UnusedBuilder<PushBack<Invariant<A, End>, T>>
+    where
+        T: UnusedConcat<Invariant<A, End>>,
+    {
+        UnusedBuilder {
+            unused: UnusedImpl::Unused,
+        }
+    }
+
+    /// Appends a type that is covariant, like `A: covariant` in `Unused!`.
+    pub const fn covariant<A: ?Sized>(self) -> UnusedBuilder<PushBack<Covariant<A, End>, T>>
+    where
+        T: UnusedConcat<Covariant<A, End>>,
+    {
+        UnusedBuilder {
+            unused: UnusedImpl::Unused,
+        }
+    }
+
+    /// Appends a type that is contravariant, like `A: contravariant` in
+    /// `Unused!`.
+    pub const fn contravariant<A: ?Sized>(self) -> UnusedBuilder<PushBack<Contravariant<A, End>, T>>
+    where
+        T: UnusedConcat<Contravariant<A, End>>,
+    {
+        UnusedBuilder {
+            unused: UnusedImpl::Unused,
+        }
+    }
+
+    /// Builds the `Unused`.
+    pub const fn build(self) -> UnusedImpl<T> {
+        self.unused
+    }
+}
+
+impl<T: UnusedInner> Clone for UnusedBuilder<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: UnusedInner> Copy for UnusedBuilder<T> {}
+
+impl<T: UnusedInner> fmt::Debug for UnusedBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UnusedBuilder").field(&self.unused).finish()
+    }
+}
+
+impl Default for UnusedBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
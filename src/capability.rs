@@ -0,0 +1,52 @@
+use core::fmt;
+
+use crate::Unused;
+
+/// A zero-sized token, which proves that its holder has the capability
+/// `Cap`.
+///
+/// A `Capability` can only be created with the `unsafe` function
+/// [`Capability::new`], so code that is not trusted to create capabilities
+/// can be checked for by looking for `unsafe`. Functions that require a
+/// capability take a `&Capability<Cap>` argument:
+///
+/// ```
+/// use unused::Capability;
+///
+/// enum NetworkAccess {}
+///
+/// fn connect(_cap: &Capability<NetworkAccess>, host: &str) -> String {
+///     format!("connected to {}", host)
+/// }
+///
+/// // SAFETY: this is the entry point of the program, which is allowed to
+/// // access the network.
+/// let network = unsafe { Capability::<NetworkAccess>::new() };
+/// assert_eq!(connect(&network, "example.com"), "connected to example.com");
+/// ```
+///
+/// `Capability` is invariant over `Cap`, and does not implement `Clone` or
+/// `Default`, so a capability cannot be created from nothing or from a
+/// different capability.
+pub struct Capability<Cap: ?Sized> {
+    _unused: Unused!(Cap: invariant),
+}
+
+impl<Cap: ?Sized> Capability<Cap> {
+    /// Creates a new `Capability`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must be allowed to use the capability `Cap`, and to give
+    /// it to the code that it passes the `Capability` to. What this means
+    /// depends on `Cap`.
+    pub const unsafe fn new() -> Self {
+        Self { _unused: Unused }
+    }
+}
+
+impl<Cap: ?Sized> fmt::Debug for Capability<Cap> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Capability").finish()
+    }
+}
@@ -0,0 +1,64 @@
+/// Declares a type alias for an [`Unused`](type@crate::Unused) whose variance
+/// depends on the active `cfg` flags.
+///
+/// Each variance is preceded by a `cfg` predicate in square brackets. The
+/// first variance whose predicate is true is used, and the type is invariant
+/// if none of them are true:
+///
+/// ```
+/// use unused::{cfg_variance, Unused};
+///
+/// cfg_variance! {
+///     /// Covariant on Linux, and invariant everywhere else.
+///     pub type PlatformUnused<T> = T:
+///         [cfg(target_os = "linux")] covariant,
+///         [cfg(not(target_os = "linux"))] invariant;
+/// }
+///
+/// struct Handle<T> {
+///     unused: PlatformUnused<T>,
+/// }
+///
+/// #[cfg(target_os = "linux")]
+/// fn shorten<'a>(handle: Handle<&'static str>) -> Handle<&'a str> {
+///     handle
+/// }
+///
+/// let handle: Handle<&str> = Handle { unused: Unused };
+/// ```
+///
+/// As the variance is chosen with `cfg` attributes, the alias is an ordinary
+/// type alias, and variance works the same as with [`Unused!`](crate::Unused!).
+#[macro_export]
+macro_rules! cfg_variance {
+    (
+        $(#[$attr:meta])*
+        $vis:vis type $name:ident $(<$($param:tt),+ $(,)?>)? = $type:ty:
+            $([cfg($pred:meta)] $variance:ident),+ $(,)?;
+    ) => {
+        $crate::__impl_cfg_variance! {
+            @[$(#[$attr])* $vis type $name $(<$($param),+>)?] [$type] []
+            $([$pred] $variance)+
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_cfg_variance {
+    (@[$($decl:tt)*] [$type:ty] [$($prev:meta),*]) => {
+        #[cfg(not(any($($prev),*)))]
+        $($decl)* = $crate::Unused!($type);
+    };
+    (
+        @[$($decl:tt)*] [$type:ty] [$($prev:meta),*]
+        [$pred:meta] $variance:ident $($rest:tt)*
+    ) => {
+        #[cfg(all($pred, not(any($($prev),*))))]
+        $($decl)* = $crate::Unused!($type: $variance);
+
+        $crate::__impl_cfg_variance! {
+            @[$($decl)*] [$type] [$($prev,)* $pred] $($rest)*
+        }
+    };
+}
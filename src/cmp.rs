@@ -0,0 +1,43 @@
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+use crate::{Contravariant, Covariant, End, Invariant};
+
+/// `Unused` is always equal to `()`.
+impl<T: UnusedInner> PartialEq<()> for UnusedImpl<T> {
+    fn eq(&self, _other: &()) -> bool {
+        true
+    }
+}
+
+impl<T: UnusedInner> PartialOrd<()> for UnusedImpl<T> {
+    fn partial_cmp(&self, _other: &()) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+/// `Unused!(T: invariant)` is always equal to the `PhantomData` with the same
+/// variance.
+impl<T> PartialEq<PhantomData<fn(T) -> T>> for UnusedImpl<Invariant<T, End>> {
+    fn eq(&self, _other: &PhantomData<fn(T) -> T>) -> bool {
+        true
+    }
+}
+
+/// `Unused!(T: covariant)` is always equal to the `PhantomData` with the same
+/// variance.
+impl<T: ?Sized> PartialEq<PhantomData<T>> for UnusedImpl<Covariant<T, End>> {
+    fn eq(&self, _other: &PhantomData<T>) -> bool {
+        true
+    }
+}
+
+/// `Unused!(T: contravariant)` is always equal to the `PhantomData` with the
+/// same variance.
+impl<T> PartialEq<PhantomData<fn(T)>> for UnusedImpl<Contravariant<T, End>> {
+    fn eq(&self, _other: &PhantomData<fn(T)>) -> bool {
+        true
+    }
+}
@@ -0,0 +1,173 @@
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+use crate::{Contravariant, Covariant, End, Invariant, Unused};
+
+/// Shortens the lifetime of a covariant reference in an `Unused`.
+///
+/// This is the same coercion that the compiler performs implicitly, and
+/// exists to make it explicit. It is also exported as `shorten_covariant`.
+///
+/// ```
+/// use unused::Unused;
+///
+/// fn shorten<'a>(unused: Unused!(&'static str: covariant)) -> Unused!(&'a str: covariant) {
+///     unused::coerce_covariant(unused)
+/// }
+/// ```
+///
+/// The lifetime cannot be lengthened:
+///
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// fn lengthen<'a>(unused: Unused!(&'a str: covariant)) -> Unused!(&'static str: covariant) {
+///     unused::coerce_covariant(unused)
+/// }
+/// ```
+pub fn coerce_covariant<'long: 'short, 'short, T: ?Sized>(
+    _unused: Unused!(&'long T: covariant),
+) -> Unused!(&'short T: covariant) {
+    UnusedImpl::Unused
+}
+
+/// Lengthens the lifetime of a contravariant reference in an `Unused`.
+///
+/// This is the same coercion that the compiler performs implicitly, and
+/// exists to make it explicit. It is also exported as `lengthen_contravariant`.
+///
+/// ```
+/// use unused::Unused;
+///
+/// fn lengthen<'a>(
+///     unused: Unused!(&'a str: contravariant),
+/// ) -> Unused!(&'static str: contravariant) {
+///     unused::coerce_contravariant(unused)
+/// }
+/// ```
+///
+/// The lifetime cannot be shortened:
+///
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// fn shorten<'a>(
+///     unused: Unused!(&'static str: contravariant),
+/// ) -> Unused!(&'a str: contravariant) {
+///     unused::coerce_contravariant(unused)
+/// }
+/// ```
+pub fn coerce_contravariant<'long: 'short, 'short, T: ?Sized>(
+    _unused: Unused!(&'short T: contravariant),
+) -> Unused!(&'long T: contravariant) {
+    UnusedImpl::Unused
+}
+
+/// Converts an `Unused` into an `Unused` of any other types and variances.
+///
+/// Prefer [`coerce_covariant`], [`coerce_contravariant`], or [`CoerceTo`]
+/// where possible.
+///
+/// As `Unused` is always zero-sized, this is equivalent to a
+/// [`transmute`](core::mem::transmute), but does not require one.
+///
+/// ```
+/// use unused::Unused;
+///
+/// let unused: Unused!(&'static str) = Unused;
+/// // SAFETY: nothing relies on the type of `unused`.
+/// let _: Unused!(u8, u16: covariant) = unsafe { unused::coerce_variance(unused) };
+/// ```
+///
+/// # Safety
+///
+/// `Unused` never contains any data, so this conversion cannot by itself
+/// cause undefined behavior. However, other code may rely on the types and
+/// variances of an `Unused`, such as an invariant lifetime used as a brand.
+/// The caller must ensure that the conversion does not break any such
+/// guarantee.
+///
+/// In particular, `U` must be valid wherever `T` is: the types of `U` may not
+/// be used in more ways than the types of `T` allow. For example, turning an
+/// `Unused!(&'a str)` into an `Unused!(&'static str)` extends a lifetime that
+/// other code may rely on.
+#[doc(alias = "transmute_variance")]
+pub unsafe fn coerce_variance<T: UnusedInner, U: UnusedInner>(
+    _unused: UnusedImpl<T>,
+) -> UnusedImpl<U> {
+    UnusedImpl::Unused
+}
+
+/// A safe conversion from one `Unused` to another with compatible variances.
+///
+/// `CoerceTo` is implemented for:
+///
+/// - shortening the lifetime of a covariant reference, as in
+///   [`coerce_covariant`],
+/// - lengthening the lifetime of a contravariant reference, as in
+///   [`coerce_contravariant`], and
+/// - loosening an invariant type to a covariant or contravariant one.
+///
+/// ```
+/// use unused::{CoerceTo, Unused};
+///
+/// let unused: Unused!(String) = Unused;
+/// let covariant: Unused!(String: covariant) = unused.coerce();
+/// ```
+///
+/// A covariant type cannot be made invariant:
+///
+/// ```compile_fail
+/// use unused::{CoerceTo, Unused};
+///
+/// let unused: Unused!(String: covariant) = Unused;
+/// let invariant: Unused!(String) = unused.coerce();
+/// ```
+pub trait CoerceTo<Target> {
+    /// Performs the coercion.
+    fn coerce(self) -> Target;
+}
+
+impl<'long: 'short, 'short, T: ?Sized> CoerceTo<Unused!(&'short T: covariant)> for Unused!(&'long T: covariant) {
+    fn coerce(self) -> Unused!(&'short T: covariant) {
+        coerce_covariant(self)
+    }
+}
+
+impl<'long: 'short, 'short, T: ?Sized> CoerceTo<Unused!(&'long T: contravariant)> for Unused!(&'short T: contravariant) {
+    fn coerce(self) -> Unused!(&'long T: contravariant) {
+        coerce_contravariant(self)
+    }
+}
+
+impl<T: ?Sized> CoerceTo<UnusedImpl<Covariant<T, End>>> for UnusedImpl<Invariant<T, End>> {
+    fn coerce(self) -> UnusedImpl<Covariant<T, End>> {
+        UnusedImpl::Unused
+    }
+}
+
+impl<T: ?Sized> CoerceTo<UnusedImpl<Contravariant<T, End>>> for UnusedImpl<Invariant<T, End>> {
+    fn coerce(self) -> UnusedImpl<Contravariant<T, End>> {
+        UnusedImpl::Unused
+    }
+}
+
+/// Converts `u` into a `V` using its [`CoerceTo`] implementation.
+///
+/// ```
+/// use unused::Unused;
+///
+/// fn shorten<'a>(unused: Unused!(&'static str: covariant)) -> Unused!(&'a str: covariant) {
+///     unused::coerce_to(unused)
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// fn lengthen<'a>(unused: Unused!(&'a str: covariant)) -> Unused!(&'static str: covariant) {
+///     unused::coerce_to(unused)
+/// }
+/// ```
+pub fn coerce_to<U: CoerceTo<V>, V>(u: U) -> V {
+    u.coerce()
+}
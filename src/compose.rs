@@ -0,0 +1,58 @@
+use crate::inner::UnusedInner;
+use crate::{Contravariant, Covariant, End, Invariant};
+
+/// Composes the variance of `Self` with the variance of `V`, giving an
+/// `UnusedInner` for `T` with the composed variance.
+///
+/// `Self` and `V` are single-type chains, such as `Covariant<(), End>`, whose
+/// types are ignored. Composing means nesting: if `F<X>` has the variance of
+/// `Self` over `X`, and `G<Y>` has the variance of `V` over `Y`, then
+/// `F<G<T>>` has the composed variance over `T`. Composing with an invariant
+/// variance is invariant, two covariant or two contravariant variances are
+/// covariant, and otherwise the result is contravariant.
+///
+/// This trait is implemented for every pair of variances. See
+/// [`ComposeVariance`].
+pub trait Compose<V, T: ?Sized> {
+    /// The composed `UnusedInner` for `T`.
+    type Output: UnusedInner;
+}
+
+/// An `UnusedInner` for `T`, with the variance of `V1` composed with the
+/// variance of `V2`.
+///
+/// ```
+/// use unused::{ComposeVariance, Contravariant, Covariant, End, Unused};
+///
+/// // A covariant function of a contravariant function is contravariant.
+/// type Composed<T> = ComposeVariance<Covariant<(), End>, Contravariant<(), End>, T>;
+///
+/// fn contravariant<'a>(
+///     unused: Unused<Composed<&'a str>>,
+/// ) -> Unused<Composed<&'static str>> {
+///     unused
+/// }
+/// ```
+pub type ComposeVariance<V1, V2, T> = <V1 as Compose<V2, T>>::Output;
+
+macro_rules! impl_compose {
+    ($($outer:ident, $inner:ident => $output:ident;)*) => {
+        $(
+            impl<A: ?Sized, B: ?Sized, T: ?Sized> Compose<$inner<B, End>, T> for $outer<A, End> {
+                type Output = $output<T, End>;
+            }
+        )*
+    };
+}
+
+impl_compose! {
+    Invariant, Invariant => Invariant;
+    Invariant, Covariant => Invariant;
+    Invariant, Contravariant => Invariant;
+    Covariant, Invariant => Invariant;
+    Covariant, Covariant => Covariant;
+    Covariant, Contravariant => Contravariant;
+    Contravariant, Invariant => Invariant;
+    Contravariant, Covariant => Contravariant;
+    Contravariant, Contravariant => Covariant;
+}
@@ -0,0 +1,37 @@
+/// A zero-sized marker for a `usize` const generic parameter.
+///
+/// Unlike type parameters, Rust allows const generic parameters to be
+/// unused. `UnusedConst` is instead useful for carrying a const value at the
+/// type level, such as alongside types in an `Unused`:
+///
+/// ```
+/// use unused::{Unused, UnusedConst};
+///
+/// struct Matrix<T, const ROWS: usize, const COLUMNS: usize> {
+///     unused: Unused!(T: covariant, UnusedConst<ROWS>, UnusedConst<COLUMNS>),
+/// }
+/// ```
+///
+/// To carry values of other types, see [`UnusedConst!`](crate::UnusedConst!) and
+/// [`UnusedMixed!`](crate::UnusedMixed!).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnusedConst<const N: usize>;
+
+macro_rules! const_markers {
+    ($($type:ident)*) => {
+        $(
+            #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $type<const V: core::primitive::$type>;
+        )*
+    };
+}
+
+/// Markers for const values of each primitive type, named after the type so
+/// that [`UnusedConst!`](crate::UnusedConst!) and [`UnusedMixed!`](crate::UnusedMixed!) can find them.
+#[allow(non_camel_case_types)]
+pub mod markers {
+    /// The marker for `usize`.
+    pub type usize<const N: core::primitive::usize> = super::UnusedConst<N>;
+
+    const_markers!(bool char u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 isize);
+}
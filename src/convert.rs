@@ -0,0 +1,166 @@
+use core::borrow::{Borrow, BorrowMut};
+use core::convert::{Infallible, TryFrom};
+use core::ops::{ControlFlow, Deref, DerefMut};
+use core::ptr::NonNull;
+use core::str::FromStr;
+
+use crate::end::End;
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+const UNIT: () = ();
+
+fn unit_mut<'a>() -> &'a mut () {
+    // SAFETY: `()` is zero-sized, so a dangling, well-aligned pointer to it
+    // is valid for reads and writes, and cannot alias any other data.
+    unsafe { NonNull::dangling().as_mut() }
+}
+
+/// `Unused` dereferences to `()`, as it contains no data.
+impl<T: UnusedInner> Deref for UnusedImpl<T> {
+    type Target = ();
+
+    fn deref(&self) -> &() {
+        &UNIT
+    }
+}
+
+impl<T: UnusedInner> DerefMut for UnusedImpl<T> {
+    fn deref_mut(&mut self) -> &mut () {
+        unit_mut()
+    }
+}
+
+impl<T: UnusedInner> AsRef<()> for UnusedImpl<T> {
+    fn as_ref(&self) -> &() {
+        &UNIT
+    }
+}
+
+impl<T: UnusedInner> AsMut<()> for UnusedImpl<T> {
+    fn as_mut(&mut self) -> &mut () {
+        unit_mut()
+    }
+}
+
+/// `Unused` can be viewed as an empty slice of `Infallible`, as there are no
+/// values that it could contain.
+impl<T: UnusedInner> AsRef<[Infallible]> for UnusedImpl<T> {
+    fn as_ref(&self) -> &[Infallible] {
+        &[]
+    }
+}
+
+/// `Unused` can be viewed as an empty array of `()`.
+impl<T: UnusedInner> AsRef<[(); 0]> for UnusedImpl<T> {
+    fn as_ref(&self) -> &[(); 0] {
+        &[]
+    }
+}
+
+impl<T: UnusedInner> Borrow<()> for UnusedImpl<T> {
+    fn borrow(&self) -> &() {
+        &UNIT
+    }
+}
+
+impl<T: UnusedInner> BorrowMut<()> for UnusedImpl<T> {
+    fn borrow_mut(&mut self) -> &mut () {
+        unit_mut()
+    }
+}
+
+impl<T: UnusedInner> From<()> for UnusedImpl<T> {
+    fn from(_: ()) -> Self {
+        Self::Unused
+    }
+}
+
+impl<T: UnusedInner> From<UnusedImpl<T>> for () {
+    fn from(_: UnusedImpl<T>) -> Self {}
+}
+
+/// `Infallible` can never be created, so this conversion can never happen.
+impl From<Infallible> for UnusedImpl<End> {
+    fn from(infallible: Infallible) -> Self {
+        match infallible {}
+    }
+}
+
+/// The never type can never be created, so this conversion can never happen.
+#[cfg(feature = "nightly")]
+impl From<!> for UnusedImpl<End> {
+    fn from(never: !) -> Self {
+        never
+    }
+}
+
+/// Both `true` and `false` are converted to `Unused`.
+impl<T: UnusedInner> From<bool> for UnusedImpl<T> {
+    fn from(_: bool) -> Self {
+        Self::Unused
+    }
+}
+
+/// Both `Continue(())` and `Break(())` are converted to `Unused`.
+impl<T: UnusedInner> From<ControlFlow<(), ()>> for UnusedImpl<T> {
+    fn from(_: ControlFlow<(), ()>) -> Self {
+        Self::Unused
+    }
+}
+
+/// `Unused` is converted to `Continue(())`.
+impl<T: UnusedInner> From<UnusedImpl<T>> for ControlFlow<(), ()> {
+    fn from(_: UnusedImpl<T>) -> Self {
+        ControlFlow::Continue(())
+    }
+}
+
+macro_rules! impl_try_from_zero {
+    ($($type:ty),* $(,)?) => {
+        $(
+            /// Only `0` is converted to `Unused`.
+            impl<T: UnusedInner> TryFrom<$type> for UnusedImpl<T> {
+                type Error = ();
+
+                fn try_from(value: $type) -> Result<Self, ()> {
+                    match value {
+                        0 => Ok(Self::Unused),
+                        _ => Err(()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_zero!(u8, u128);
+
+/// Only the empty string and `"Unused"`, which is the
+/// [`Display`](core::fmt::Display) output of `Unused`, are converted to
+/// `Unused`.
+///
+/// This is stricter than the [`FromStr`] implementation, which accepts every
+/// string.
+impl<'a, T: UnusedInner> TryFrom<&'a str> for UnusedImpl<T> {
+    type Error = ();
+
+    fn try_from(value: &'a str) -> Result<Self, ()> {
+        match value {
+            "" | "Unused" => Ok(Self::Unused),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Every string is parsed as `Unused`.
+///
+/// The string `"Unused"`, which is the [`Display`](core::fmt::Display)
+/// output of `Unused`, is therefore also parsed as `Unused`.
+impl<T: UnusedInner> FromStr for UnusedImpl<T> {
+    type Err = Infallible;
+
+    fn from_str(_s: &str) -> Result<Self, Infallible> {
+        Ok(Self::Unused)
+    }
+}
@@ -0,0 +1,91 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::Unused;
+
+/// A zero-sized, [`Cow`](https://doc.rust-lang.org/std/borrow/enum.Cow.html)-like
+/// marker for either a borrowed `&'a T` or an owned `T`.
+///
+/// `UnusedCow` shows how `Unused`s with different types and variances can be
+/// combined with an enum. Both variants are zero-sized, so `UnusedCow` only
+/// stores which variant it is:
+///
+/// ```
+/// use unused::UnusedCow;
+///
+/// let borrowed: UnusedCow<'_, String> = UnusedCow::borrowed();
+/// assert!(borrowed.is_borrowed());
+/// assert!(borrowed.into_owned().is_owned());
+/// assert_eq!(core::mem::size_of_val(&borrowed), 1);
+/// ```
+///
+/// Every `UnusedCow` compares as equal, as it does not contain any data.
+pub enum UnusedCow<'a, T: ?Sized> {
+    /// A borrowed `&'a T`.
+    Borrowed(Unused!(&'a T: covariant)),
+    /// An owned `T`.
+    Owned(Unused!(T: invariant)),
+}
+
+impl<'a, T: ?Sized> UnusedCow<'a, T> {
+    /// Creates a new, borrowed `UnusedCow`.
+    pub const fn borrowed() -> Self {
+        Self::Borrowed(Unused)
+    }
+
+    /// Creates a new, owned `UnusedCow`.
+    pub const fn owned() -> Self {
+        Self::Owned(Unused)
+    }
+
+    /// Returns whether this `UnusedCow` is borrowed.
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Returns whether this `UnusedCow` is owned.
+    pub const fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Converts this `UnusedCow` into an owned `UnusedCow`.
+    pub const fn into_owned(self) -> Self {
+        Self::owned()
+    }
+}
+
+impl<T: ?Sized> Clone for UnusedCow<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for UnusedCow<'_, T> {}
+
+impl<T: ?Sized> fmt::Debug for UnusedCow<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Borrowed(unused) => f.debug_tuple("Borrowed").field(unused).finish(),
+            Self::Owned(unused) => f.debug_tuple("Owned").field(unused).finish(),
+        }
+    }
+}
+
+/// An `UnusedCow` is owned by default.
+impl<T: ?Sized> Default for UnusedCow<'_, T> {
+    fn default() -> Self {
+        Self::owned()
+    }
+}
+
+impl<T: ?Sized> PartialEq for UnusedCow<'_, T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized> Eq for UnusedCow<'_, T> {}
+
+impl<T: ?Sized> Hash for UnusedCow<'_, T> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
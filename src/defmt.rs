@@ -0,0 +1,11 @@
+use defmt::{Format, Formatter};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// `Unused` is formatted as `Unused`.
+impl<T: UnusedInner> Format for UnusedImpl<T> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "Unused");
+    }
+}
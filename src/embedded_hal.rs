@@ -0,0 +1,32 @@
+use core::convert::Infallible;
+
+use embedded_hal::digital::{ErrorType, InputPin, OutputPin};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+impl<T: UnusedInner> ErrorType for UnusedImpl<T> {
+    type Error = Infallible;
+}
+
+/// Setting `Unused` high or low does nothing.
+impl<T: UnusedInner> OutputPin for UnusedImpl<T> {
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+/// `Unused` is always read as low.
+impl<T: UnusedInner> InputPin for UnusedImpl<T> {
+    fn is_high(&mut self) -> Result<bool, Infallible> {
+        Ok(false)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Infallible> {
+        Ok(true)
+    }
+}
@@ -1,6 +1,7 @@
 use core::fmt;
 
 use crate::inner::UnusedInner;
+use crate::variance::Variance;
 
 /// An `UnusedInner` that does not contain a type.
 ///
@@ -11,9 +12,15 @@ use crate::inner::UnusedInner;
 pub enum End {}
 
 impl UnusedInner for End {
+    const ARITY: usize = 0;
+
     fn inconstruable(self) -> End {
         self
     }
+
+    fn variance(_index: usize) -> Option<Variance> {
+        None
+    }
 }
 
 impl fmt::Display for End {
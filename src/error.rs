@@ -0,0 +1,14 @@
+use std::error::Error;
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// `Unused` can be used as an error type, such as in mock implementations.
+///
+/// It has no source, and is displayed as `Unused`. This requires the `std`
+/// feature.
+impl<T: UnusedInner> Error for UnusedImpl<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
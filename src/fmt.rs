@@ -0,0 +1,137 @@
+use core::fmt;
+use core::ptr;
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+use crate::Unused;
+
+/// `Unused` is displayed as `Unused`.
+impl<T: UnusedInner> fmt::Display for UnusedImpl<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("Unused")
+    }
+}
+
+macro_rules! impl_numeric_fmt {
+    ($($trait:ident),* $(,)?) => {
+        $(
+            /// `Unused` is formatted as zero.
+            impl<T: UnusedInner> fmt::$trait for UnusedImpl<T> {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$trait::fmt(&0u8, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_fmt!(Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp);
+
+/// `Unused` is formatted as a null pointer, as it does not point to anything.
+impl<T: UnusedInner> fmt::Pointer for UnusedImpl<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&ptr::null::<()>(), f)
+    }
+}
+
+/// Writing to `Unused` discards everything written.
+impl<T: UnusedInner> fmt::Write for UnusedImpl<T> {
+    fn write_str(&mut self, _s: &str) -> fmt::Result {
+        Ok(())
+    }
+
+    fn write_char(&mut self, _c: char) -> fmt::Result {
+        Ok(())
+    }
+}
+
+/// A writer for an unused `T` that discards everything written, but counts
+/// the number of chars and bytes written.
+///
+/// ```
+/// use core::fmt::Write;
+///
+/// use unused::WriteSink;
+///
+/// let mut sink = WriteSink::<String>::new();
+/// sink.write_str("hello").unwrap();
+/// write!(sink, ", {}", 42).unwrap();
+/// sink.write_char('é').unwrap();
+/// assert_eq!(sink.chars(), 10);
+/// assert_eq!(sink.bytes(), 11);
+/// ```
+///
+/// The counts saturate at [`usize::MAX`] instead of overflowing.
+///
+/// `WriteSink` only stores its counts, and, like `Unused`, it is always
+/// [`Send`] and [`Sync`], whatever `T` is. It is [`Clone`] but not [`Copy`],
+/// so that the counts are not lost by writing to an implicit copy.
+pub struct WriteSink<T: ?Sized> {
+    _unused: Unused!(T),
+    pub(crate) chars: usize,
+    pub(crate) bytes: usize,
+}
+
+impl<T: ?Sized> WriteSink<T> {
+    /// Creates a new `WriteSink`, which has not been written to.
+    pub const fn new() -> Self {
+        Self {
+            _unused: Unused,
+            chars: 0,
+            bytes: 0,
+        }
+    }
+
+    /// Returns the number of chars written to this `WriteSink` with
+    /// [`fmt::Write`].
+    pub const fn chars(&self) -> usize {
+        self.chars
+    }
+
+    /// Returns the number of bytes written to this `WriteSink`.
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+impl<T: ?Sized> fmt::Write for WriteSink<T> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.chars = self.chars.saturating_add(s.chars().count());
+        self.bytes = self.bytes.saturating_add(s.len());
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.chars = self.chars.saturating_add(1);
+        self.bytes = self.bytes.saturating_add(c.len_utf8());
+        Ok(())
+    }
+}
+
+impl<T: ?Sized> Clone for WriteSink<T> {
+    fn clone(&self) -> Self {
+        Self {
+            _unused: Unused,
+            chars: self.chars,
+            bytes: self.bytes,
+        }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for WriteSink<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WriteSink")
+            .field("chars", &self.chars)
+            .field("bytes", &self.bytes)
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Default for WriteSink<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,45 @@
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// Calling an `Unused` with no arguments does nothing.
+impl<T: UnusedInner> FnOnce<()> for UnusedImpl<T> {
+    type Output = ();
+
+    #[inline]
+    extern "rust-call" fn call_once(self, _args: ()) {}
+}
+
+impl<T: UnusedInner> FnMut<()> for UnusedImpl<T> {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, _args: ()) {}
+}
+
+impl<T: UnusedInner> Fn<()> for UnusedImpl<T> {
+    #[inline]
+    extern "rust-call" fn call(&self, _args: ()) {}
+}
+
+/// Calling an `Unused` with one argument discards the argument and returns an
+/// `Unused`.
+impl<T: UnusedInner, A> FnOnce<(A,)> for UnusedImpl<T> {
+    type Output = Self;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, _args: (A,)) -> Self {
+        Self::Unused
+    }
+}
+
+impl<T: UnusedInner, A> FnMut<(A,)> for UnusedImpl<T> {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, _args: (A,)) -> Self {
+        Self::Unused
+    }
+}
+
+impl<T: UnusedInner, A> Fn<(A,)> for UnusedImpl<T> {
+    #[inline]
+    extern "rust-call" fn call(&self, _args: (A,)) -> Self {
+        Self::Unused
+    }
+}
@@ -0,0 +1,63 @@
+use frunk::{HCons, HNil};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+use crate::{Contravariant, Covariant, End, Invariant};
+
+/// An [`HList`](frunk::hlist::HList) type that can be used as the types of an
+/// `Unused`.
+///
+/// See [`UnusedHList`], [`CovariantHList`], and [`ContravariantHList`].
+///
+/// This trait is sealed.
+pub trait HListUnused: frunk::hlist::HList + sealed::Sealed {
+    /// The types of the `HList`, each invariant.
+    type Invariant: UnusedInner;
+
+    /// The types of the `HList`, each covariant.
+    type Covariant: UnusedInner;
+
+    /// The types of the `HList`, each contravariant.
+    type Contravariant: UnusedInner;
+}
+
+impl HListUnused for HNil {
+    type Invariant = End;
+    type Covariant = End;
+    type Contravariant = End;
+}
+
+impl<H, T: HListUnused> HListUnused for HCons<H, T> {
+    type Invariant = Invariant<H, T::Invariant>;
+    type Covariant = Covariant<H, T::Covariant>;
+    type Contravariant = Contravariant<H, T::Contravariant>;
+}
+
+/// An `Unused` of the types of an [`HList`](frunk::hlist::HList), each invariant.
+///
+/// ```
+/// use frunk::HList;
+/// use unused::{Unused, UnusedHList};
+///
+/// let unused: UnusedHList<HList![u8, u16, u32]> = Unused;
+/// // is the same as:
+/// let unused: Unused!(u8, u16, u32) = unused;
+/// ```
+pub type UnusedHList<L> = UnusedImpl<<L as HListUnused>::Invariant>;
+
+/// An `Unused` of the types of an [`HList`](frunk::hlist::HList), each covariant.
+pub type CovariantHList<L> = UnusedImpl<<L as HListUnused>::Covariant>;
+
+/// An `Unused` of the types of an [`HList`](frunk::hlist::HList), each
+/// contravariant.
+pub type ContravariantHList<L> = UnusedImpl<<L as HListUnused>::Contravariant>;
+
+mod sealed {
+    use frunk::{HCons, HNil};
+
+    pub trait Sealed {}
+
+    impl Sealed for HNil {}
+
+    impl<H, T: Sealed> Sealed for HCons<H, T> {}
+}
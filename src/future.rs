@@ -0,0 +1,61 @@
+use core::convert::Infallible;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+use crate::Unused;
+
+/// `Unused` is a future that never completes.
+///
+/// This is useful as a placeholder, such as for a branch of a `select!`.
+///
+/// The waker in the `Context` is neither stored nor woken. Since polling again
+/// can never make progress, waking it would only make the executor poll this
+/// future in a busy loop.
+impl<T: UnusedInner> Future for UnusedImpl<T> {
+    type Output = Infallible;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Infallible> {
+        Poll::Pending
+    }
+}
+
+/// An `Unused` used as a future that never completes.
+///
+/// ```
+/// use core::future::Future;
+/// use unused::{PendingForever, Unused};
+///
+/// fn never() -> impl Future {
+///     let pending: PendingForever<String> = Unused;
+///     pending
+/// }
+/// ```
+pub type PendingForever<T> = Unused!(T);
+
+/// A zero-sized future for an unused `T` that completes immediately.
+///
+/// This is the counterpart of `Unused`, which never completes when used as a
+/// future.
+pub struct ReadyUnused<T: ?Sized> {
+    _unused: Unused!(T),
+}
+
+impl<T: ?Sized> ReadyUnused<T> {
+    /// Creates a new `ReadyUnused`.
+    pub const fn new() -> Self {
+        Self { _unused: Unused }
+    }
+}
+
+impl<T: ?Sized> Future for ReadyUnused<T> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Ready(())
+    }
+}
+
+impl_marker!(ReadyUnused);
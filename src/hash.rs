@@ -0,0 +1,42 @@
+use core::hash::{BuildHasher, Hasher};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// A [`Hasher`] that ignores everything written to it, and always finishes
+/// with `0`.
+///
+/// This is the hasher built by [`Unused`](type@crate::Unused), which
+/// implements [`BuildHasher`].
+///
+/// ```
+/// use core::hash::{BuildHasher, Hasher};
+///
+/// use unused::Unused;
+///
+/// let mut hasher = <Unused!(u8)>::default().build_hasher();
+/// hasher.write(b"ignored");
+/// assert_eq!(hasher.finish(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoopHasher;
+
+impl Hasher for NoopHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn write(&mut self, _bytes: &[u8]) {}
+}
+
+/// `Unused` builds a [`NoopHasher`], so every value hashes to `0`.
+impl<T: UnusedInner> BuildHasher for UnusedImpl<T> {
+    type Hasher = NoopHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> NoopHasher {
+        NoopHasher
+    }
+}
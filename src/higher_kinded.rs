@@ -0,0 +1,61 @@
+use crate::Unused;
+
+/// A type constructor, which produces a type when applied to a type `T`.
+///
+/// Type constructors are represented by marker types, such as [`IdentityF`]
+/// and [`PairF`], which are never constructed.
+pub trait TypeConstructor<T: ?Sized> {
+    /// The type produced by applying this type constructor to `T`.
+    type Applied: ?Sized;
+}
+
+/// The type produced by applying the type constructor `F` to `T`.
+pub type Apply<F, T> = <F as TypeConstructor<T>>::Applied;
+
+/// An `Unused` for the type produced by applying the type constructor `F` to
+/// `T`, which is covariant over that type.
+pub type UnusedApply<F, T> = Unused!(Apply<F, T>: covariant);
+
+/// A type constructor that can be mapped over.
+///
+/// Since `Unused` stores no values, mapping only changes the type, but the
+/// function is still required, so that an `F<A>` can only be mapped into an
+/// `F<B>` if an `A` could be turned into a `B`.
+///
+/// ```
+/// use unused::{Functor, PairF, Unused, UnusedApply};
+///
+/// let pair: UnusedApply<PairF, u8> = Unused;
+/// let pair: UnusedApply<PairF, String> = PairF::fmap(pair, |value| value.to_string());
+/// let _: Unused!((String, String): covariant) = pair;
+/// ```
+pub trait Functor {
+    /// Maps an `Unused` for `F<A>` into an `Unused` for `F<B>`.
+    fn fmap<A, B>(fa: UnusedApply<Self, A>, f: impl FnOnce(A) -> B) -> UnusedApply<Self, B>
+    where
+        Self: TypeConstructor<A> + TypeConstructor<B>,
+    {
+        let _ = (fa, f);
+        Unused
+    }
+}
+
+/// The identity type constructor, which maps `T` to `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IdentityF {}
+
+impl<T: ?Sized> TypeConstructor<T> for IdentityF {
+    type Applied = T;
+}
+
+impl Functor for IdentityF {}
+
+/// The pair type constructor, which maps `T` to `(T, T)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PairF {}
+
+impl<T> TypeConstructor<T> for PairF {
+    type Applied = (T, T);
+}
+
+impl Functor for PairF {}
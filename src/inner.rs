@@ -1,4 +1,5 @@
-use crate::End;
+use crate::variance::Variance;
+use crate::{Contravariant, Covariant, End, Invariant};
 
 /// Represents a type that can be used as a `T` for
 /// [`Unused<T>`](type@crate::Unused).
@@ -14,7 +15,81 @@ use crate::End;
 /// When implemented for [`End`](crate::End), the type ends the chain of
 /// `UnusedInner`s.
 ///
+/// When implemented for tuples of up to eight `UnusedInner`s, the type
+/// combines multiple chains. See [`merge`](crate::merge) and
+/// [`split`](crate::split).
+///
 /// This trait is sealed.
-pub trait UnusedInner: Sized + Copy + Send + Sync + Unpin {
+// SEALED: this trait cannot be implemented outside this crate. It is declared
+// in a private module, so it cannot be named downstream, and it requires
+// `sealed::Sealed`, which is only implemented for the types below.
+pub trait UnusedInner: Sized + Copy + Send + Sync + Unpin + sealed::Sealed {
+    /// The number of types in the chain.
+    const ARITY: usize;
+
+    /// Whether this type is zero-sized, which is always `true`.
+    const ZERO_SIZED: bool = core::mem::size_of::<Self>() == 0;
+
     fn inconstruable(self) -> End;
+
+    /// Returns the variance of the type at `index` in the chain, or `None` if
+    /// `index` is at least `ARITY`.
+    fn variance(index: usize) -> Option<Variance>;
+}
+
+/// Implements `UnusedInner` for a tuple of `UnusedInner`s.
+///
+/// A tuple can never be constructed, as its first element cannot be.
+macro_rules! impl_tuple_unused {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<$first: UnusedInner, $($rest: UnusedInner),*> UnusedInner for ($first, $($rest,)*) {
+            const ARITY: usize = $first::ARITY $(+ $rest::ARITY)*;
+
+            fn inconstruable(self) -> End {
+                self.0.inconstruable()
+            }
+
+            fn variance(mut index: usize) -> Option<Variance> {
+                let chains: &[(usize, fn(usize) -> Option<Variance>)] = &[
+                    ($first::ARITY, $first::variance),
+                    $(($rest::ARITY, $rest::variance),)*
+                ];
+                for &(arity, variance) in chains {
+                    if index < arity {
+                        return variance(index);
+                    }
+                    index -= arity;
+                }
+                None
+            }
+        }
+
+        impl<$first: UnusedInner, $($rest: UnusedInner),*> sealed::Sealed
+            for ($first, $($rest,)*)
+        {
+        }
+    };
+}
+
+impl_tuple_unused!(A);
+impl_tuple_unused!(A, B);
+impl_tuple_unused!(A, B, C);
+impl_tuple_unused!(A, B, C, D);
+impl_tuple_unused!(A, B, C, D, E);
+impl_tuple_unused!(A, B, C, D, E, F);
+impl_tuple_unused!(A, B, C, D, E, F, G);
+impl_tuple_unused!(A, B, C, D, E, F, G, H);
+
+mod sealed {
+    use super::*;
+
+    pub trait Sealed {}
+
+    impl Sealed for End {}
+
+    impl<T: ?Sized, N: UnusedInner> Sealed for Invariant<T, N> {}
+
+    impl<T: ?Sized, N: UnusedInner> Sealed for Covariant<T, N> {}
+
+    impl<T: ?Sized, N: UnusedInner> Sealed for Contravariant<T, N> {}
 }
@@ -1,29 +1,42 @@
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
 
 use crate::inner::UnusedInner;
+use crate::variance::Variance;
 use crate::End;
 
 /// An `UnusedInner` that is invariant over `T`
 pub struct Invariant<T: ?Sized, N: UnusedInner> {
-    _t: fn(T) -> T,
+    _t: PhantomData<fn(T) -> T>,
     next: N,
 }
 
 impl<T: ?Sized, N: UnusedInner> UnusedInner for Invariant<T, N> {
+    const ARITY: usize = N::ARITY + 1;
+
     fn inconstruable(self) -> End {
         self.next.inconstruable()
     }
+
+    fn variance(index: usize) -> Option<Variance> {
+        match index {
+            0 => Some(Variance::Invariant),
+            _ => N::variance(index - 1),
+        }
+    }
 }
 
 impl<T: ?Sized, N: UnusedInner> fmt::Debug for Invariant<T, N> {
+    #[allow(unreachable_code)]
     fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.inconstruable() {}
     }
 }
 
 impl<T: ?Sized, N: UnusedInner> fmt::Display for Invariant<T, N> {
+    #[allow(unreachable_code)]
     fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.inconstruable() {}
     }
@@ -31,13 +44,14 @@ impl<T: ?Sized, N: UnusedInner> fmt::Display for Invariant<T, N> {
 
 impl<T: ?Sized, N: UnusedInner> Clone for Invariant<T, N> {
     fn clone(&self) -> Self {
-        match self.inconstruable() {}
+        *self
     }
 }
 
 impl<T: ?Sized, N: UnusedInner> Copy for Invariant<T, N> {}
 
 impl<T: ?Sized, N: UnusedInner> PartialEq for Invariant<T, N> {
+    #[allow(unreachable_code)]
     fn eq(&self, _other: &Self) -> bool {
         match self.inconstruable() {}
     }
@@ -46,18 +60,20 @@ impl<T: ?Sized, N: UnusedInner> PartialEq for Invariant<T, N> {
 impl<T: ?Sized, N: UnusedInner> Eq for Invariant<T, N> {}
 
 impl<T: ?Sized, N: UnusedInner> PartialOrd for Invariant<T, N> {
-    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
-        match self.inconstruable() {}
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl<T: ?Sized, N: UnusedInner> Ord for Invariant<T, N> {
+    #[allow(unreachable_code)]
     fn cmp(&self, _other: &Self) -> Ordering {
         match self.inconstruable() {}
     }
 }
 
 impl<T: ?Sized, N: UnusedInner> Hash for Invariant<T, N> {
+    #[allow(unreachable_code)]
     fn hash<H: Hasher>(&self, _state: &mut H) {
         match self.inconstruable() {}
     }
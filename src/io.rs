@@ -0,0 +1,43 @@
+use std::io;
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+use crate::WriteSink;
+
+/// Writing to `Unused` discards everything written.
+impl<T: UnusedInner> io::Write for UnusedImpl<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writing to a `WriteSink` counts the bytes written, and discards them. The
+/// bytes may not be UTF-8, so they are not counted as chars.
+impl<T: ?Sized> io::Write for WriteSink<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.bytes = self.bytes.saturating_add(buf.len());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reading from `Unused` always reaches the end immediately.
+impl<T: UnusedInner> io::Read for UnusedImpl<T> {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        Ok(0)
+    }
+}
+
+/// Seeking `Unused` always stays at the start, like seeking an empty stream.
+impl<T: UnusedInner> io::Seek for UnusedImpl<T> {
+    fn seek(&mut self, _pos: io::SeekFrom) -> io::Result<u64> {
+        Ok(0)
+    }
+}
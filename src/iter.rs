@@ -0,0 +1,34 @@
+use core::convert::Infallible;
+use core::iter::{self, Empty, FromIterator};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// Iterating over `Unused` never yields anything.
+///
+/// `Unused` is not an [`Iterator`] itself, as the methods of `Iterator` would
+/// shadow those of traits such as [`PartialEq`] and [`Ord`], so `unused.eq(&other)`
+/// and `unused.max(other)` would no longer compile.
+impl<T: UnusedInner> IntoIterator for UnusedImpl<T> {
+    type Item = Infallible;
+    type IntoIter = Empty<Infallible>;
+
+    fn into_iter(self) -> Empty<Infallible> {
+        iter::empty()
+    }
+}
+
+/// Collecting into `Unused` consumes the iterator and discards every item.
+impl<T: UnusedInner, I> FromIterator<I> for UnusedImpl<T> {
+    fn from_iter<It: IntoIterator<Item = I>>(iter: It) -> Self {
+        for _ in iter {}
+        Self::Unused
+    }
+}
+
+/// Extending `Unused` consumes the iterator and discards every item.
+impl<T: UnusedInner, I> Extend<I> for UnusedImpl<T> {
+    fn extend<It: IntoIterator<Item = I>>(&mut self, iter: It) {
+        for _ in iter {}
+    }
+}
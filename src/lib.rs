@@ -13,6 +13,167 @@
 //!
 //! `unused` supports `no_std`.
 //!
+//! The `std` feature enables implementations of traits from `std`, such as
+//! [`std::io::Write`](https://doc.rust-lang.org/std/io/trait.Write.html) and
+//! [`std::error::Error`](https://doc.rust-lang.org/std/error/trait.Error.html).
+//!
+//! ## `bincode` Support
+//!
+//! With the `bincode` feature enabled, `Unused` implements
+//! [`Encode`](https://docs.rs/bincode/2/bincode/enc/trait.Encode.html) and
+//! [`Decode`](https://docs.rs/bincode/2/bincode/de/trait.Decode.html) from
+//! `bincode` 2, and is encoded as zero bytes.
+//!
+//! ## `defmt` Support
+//!
+//! With the `defmt` feature enabled, `Unused` implements
+//! [`Format`](https://docs.rs/defmt/1/defmt/trait.Format.html), and is
+//! formatted as `Unused`. This does not require `std` or `alloc`.
+//!
+//! ## `embedded-hal` Support
+//!
+//! With the `embedded-hal` feature enabled, `Unused` implements
+//! [`OutputPin`](https://docs.rs/embedded-hal/1/embedded_hal/digital/trait.OutputPin.html)
+//! and
+//! [`InputPin`](https://docs.rs/embedded-hal/1/embedded_hal/digital/trait.InputPin.html),
+//! so it can be used as a pin that does nothing when set, and is always read
+//! as low. This is useful for testing embedded code without hardware, and
+//! does not require `std` or `alloc`.
+//!
+//! ## `frunk` Support
+//!
+//! With the `frunk` feature enabled, the types of an
+//! [`HList`](https://docs.rs/frunk/0.4/frunk/hlist/trait.HList.html) can be
+//! used as the types of an `Unused` with `UnusedHList`, `CovariantHList`, and
+//! `ContravariantHList`.
+//!
+//! ## `num-traits` Support
+//!
+//! With the `num-traits` feature enabled, `Unused` implements
+//! [`Zero`](https://docs.rs/num-traits/0.2/num_traits/identities/trait.Zero.html),
+//! [`One`](https://docs.rs/num-traits/0.2/num_traits/identities/trait.One.html),
+//! and [`Num`](https://docs.rs/num-traits/0.2/num_traits/trait.Num.html).
+//!
+//! ## `postcard` Support
+//!
+//! With the `postcard` feature enabled, which also enables the `serde`
+//! feature, `Unused` implements
+//! [`MaxSize`](https://docs.rs/postcard/1/postcard/experimental/max_size/trait.MaxSize.html)
+//! from `postcard` 1. `Unused` is serialized by `postcard` as zero bytes, so
+//! its `POSTCARD_MAX_SIZE` is zero. This does not require `std` or `alloc`.
+//!
+//! ## Rayon Support
+//!
+//! With the `rayon` feature enabled, `Unused` and references to it can be
+//! iterated over in parallel, with
+//! [`IntoParallelIterator`](https://docs.rs/rayon/1/rayon/iter/trait.IntoParallelIterator.html),
+//! like they can be iterated over with [`IntoIterator`]. They never yield
+//! anything.
+//!
+//! ## rkyv Support
+//!
+//! With the `rkyv` feature enabled, `Unused` implements
+//! [`Archive`](https://docs.rs/rkyv/0.8/rkyv/trait.Archive.html),
+//! [`Serialize`](https://docs.rs/rkyv/0.8/rkyv/trait.Serialize.html), and
+//! [`Deserialize`](https://docs.rs/rkyv/0.8/rkyv/trait.Deserialize.html).
+//! `Unused` is archived as itself, and takes up no space.
+//!
+//! ## Schemars Support
+//!
+//! With the `schemars` feature enabled, `Unused` implements
+//! [`JsonSchema`](https://docs.rs/schemars/1/schemars/trait.JsonSchema.html),
+//! with the same schema as `()`, which is `null`.
+//!
+//! To leave an `Unused` field out of a schema entirely, skip it with
+//! `#[serde(skip)]` or `#[schemars(skip)]`.
+//!
+//! ## Serde Support
+//!
+//! With the `serde` feature enabled, `Unused` implements
+//! [`Serialize`](https://docs.rs/serde/1/serde/trait.Serialize.html) and
+//! [`Deserialize`](https://docs.rs/serde/1/serde/trait.Deserialize.html).
+//!
+//! `Unused` is always serialized as a unit, which is `null` in JSON. This
+//! representation is stable. When deserializing from a human-readable format,
+//! a `null`, an empty map, or a missing field is also accepted.
+//!
+//! ## `typenum` Support
+//!
+//! Any type can be used in an `Unused`, including the type-level numbers of
+//! [`typenum`](https://docs.rs/typenum/1). With the `typenum` feature enabled,
+//! `UnusedTypenumN<N>` is an `Unused` for the `typenum` unsigned integer with
+//! the const generic value `N`, and a covariant `Unused` of a `typenum`
+//! unsigned integer can return its value with `typenum_value`.
+//!
+//! ## `async` Support
+//!
+//! With the `async` feature enabled, `Unused` implements
+//! [`Future`](core::future::Future), and never completes. `PendingForever` is
+//! an alias for an `Unused` used in this way, and `ReadyUnused` is a future
+//! that completes immediately.
+//!
+//! ## Comparing With `()`
+//!
+//! With the `unit_cmp` feature enabled, `Unused` can be compared with `()`,
+//! and is always equal to it:
+//!
+//! ```
+//! # #[cfg(feature = "unit_cmp")]
+//! # {
+//! use unused::Unused;
+//!
+//! let unused: Unused!(u8) = Unused;
+//! assert!(unused == ());
+//! assert!(unused >= ());
+//! # }
+//! ```
+//!
+//! This is behind a feature, as having more than one `PartialEq`
+//! implementation can break type inference in generic code. For the same
+//! reason, `()` cannot be compared with `Unused`, as that would affect every
+//! comparison of `()` in every crate that is compiled with this feature.
+//!
+//! The `unit_cmp` feature also allows an `Unused` for a single type to be
+//! compared with the `PhantomData` that is usually written for its variance,
+//! as described in [Converting From and To
+//! `PhantomData`](#converting-from-and-to-phantomdata):
+//!
+//! ```
+//! # #[cfg(feature = "unit_cmp")]
+//! # {
+//! use std::marker::PhantomData;
+//!
+//! use unused::Unused;
+//!
+//! let unused: Unused!(u8: covariant) = Unused;
+//! assert!(unused == PhantomData::<u8>);
+//! # }
+//! ```
+//!
+//! ## `wasm-bindgen` Support
+//!
+//! With the `wasm-bindgen` feature enabled, `Unused` can be passed to and from
+//! JavaScript with [`wasm-bindgen`](https://docs.rs/wasm-bindgen/0.2), in the
+//! same way as `()`. This allows `Unused` to be used as a public field of a
+//! `#[wasm_bindgen]` struct.
+//!
+//! ## Nightly Support
+//!
+//! With the `nightly` feature enabled, which requires a nightly compiler,
+//! `Unused` implements [`Try`](core::ops::Try), so that the `?` operator can
+//! be used on any `Result` or `Option` inside of a function that returns an
+//! `Unused`. `Unused` also implements the [`Fn`] traits: calling it with no
+//! arguments returns `()`, and calling it with one argument returns an
+//! `Unused`, so it can be used where a no-op closure is expected.
+//!
+//! `Unused` is also an [`Allocator`](core::alloc::Allocator) that always fails
+//! to allocate, so that a collection such as `Vec<u8, Unused!(Tag)>` can never
+//! hold any data.
+//!
+//! Finally, `Unused` implements [`Step`](core::iter::Step). There is only one
+//! `Unused`, so a range such as `Unused..=Unused` yields a single `Unused`,
+//! and `Unused..Unused` yields nothing.
+//!
 //! ## Example
 //!
 //! Imagine we have a struct `LazyFromStr`, which contains a <code>&'static [str]</code> and can
@@ -98,30 +259,251 @@
 //!
 //! [By default, `Unused` makes generics invariant](Unused!#variances).
 //!
+//! ## Converting From and To `PhantomData`
+//!
+//! To allow for incremental migration, `Unused` can be converted to and from a
+//! [`PhantomData`](core::marker::PhantomData) with the same variances:
+//!
+//! ```
+//! use std::marker::PhantomData;
+//!
+//! use unused::Unused;
+//!
+//! let unused: Unused!(u8: covariant, u16: contravariant) = Unused;
+//!
+//! let phantom = unused.phantom();
+//! // or:
+//! let phantom: PhantomData<_> = unused.into();
+//!
+//! let unused: Unused!(u8: covariant, u16: contravariant) = phantom.into();
+//!
+//! // An `Unused` for a single type is converted the same way:
+//! let single: Unused!(u8) = Unused;
+//! let phantom: PhantomData<_> = single.into();
+//! let single: Unused!(u8) = phantom.into();
+//! ```
+//!
+//! An `Unused` for a single type can also be converted to and from the
+//! `PhantomData` that is usually written for that variance, with
+//! [`into_phantom_variance`] and [`from_phantom_variance`]. With the
+//! `unit_cmp` feature, they can also be compared:
+//!
+//! | `Unused`                    | `PhantomData`               |
+//! |-----------------------------|-----------------------------|
+//! | `Unused!(T: invariant)`     | `PhantomData<fn(T) -> T>`   |
+//! | `Unused!(T: covariant)`     | `PhantomData<T>`            |
+//! | `Unused!(T: contravariant)` | `PhantomData<fn(T)>`        |
+//!
+//! The auto traits are where `Unused` and `PhantomData` differ. A
+//! `PhantomData<X>` is [`Send`] and [`Sync`] only if `X` is, while an
+//! `Unused!(X)` always is:
+//!
+//! | `X`              | `PhantomData<X>: Send` | `PhantomData<X>: Sync` | `Unused!(X)`    |
+//! |------------------|------------------------|------------------------|-----------------|
+//! | `String`         | yes                    | yes                    | `Send` + `Sync` |
+//! | `Arc<u8>`        | yes                    | yes                    | `Send` + `Sync` |
+//! | `Cell<u8>`       | yes                    | no                     | `Send` + `Sync` |
+//! | `RefCell<u8>`    | yes                    | no                     | `Send` + `Sync` |
+//! | `MutexGuard<u8>` | no                     | yes                    | `Send` + `Sync` |
+//! | `Rc<u8>`         | no                     | no                     | `Send` + `Sync` |
+//! | `*mut u8`        | no                     | no                     | `Send` + `Sync` |
+//!
+//! The `PhantomData` returned by `phantom` or [`into_phantom`] is over the
+//! types of the `Unused` rather than `X`, so it keeps these auto traits. A
+//! `PhantomData<X>` returned by [`into_phantom_variance`] does not.
+//!
+//! ## Statics
+//!
+//! `Unused` is always [`Send`] and [`Sync`], and has no interior mutability,
+//! so a struct with an `Unused` field can be used in a `static`, even if the
+//! unused types cannot:
+//!
+//! ```
+//! use std::rc::Rc;
+//!
+//! use unused::Unused;
+//!
+//! struct Registry<T> {
+//!     name: &'static str,
+//!     unused: Unused!(T),
+//! }
+//!
+//! static REGISTRY: Registry<Rc<String>> = Registry {
+//!     name: "registry",
+//!     unused: Unused,
+//! };
+//! ```
+//!
+//! ## Dropping
+//!
+//! `Unused` is always [`Copy`], so it never implements [`Drop`], and dropping
+//! it does nothing, whatever its types are:
+//!
+//! ```
+//! use unused::Unused;
+//!
+//! assert!(!core::mem::needs_drop::<Unused!(String)>());
+//! ```
+//!
+//! This is the same as [`PhantomData`](core::marker::PhantomData). Unlike
+//! `PhantomData<T>`, which is used to tell the drop checker that a type owns a
+//! `T`, `Unused` never indicates ownership of its types.
+//!
 //! See the [`Unused!`] macro for more examples.
 
 #![no_std]
+#![cfg_attr(
+    feature = "nightly",
+    feature(
+        allocator_api,
+        fn_traits,
+        never_type,
+        step_trait,
+        try_trait_v2,
+        try_trait_v2_residual,
+        unboxed_closures
+    )
+)]
+
+#[cfg(feature = "std")]
+extern crate std;
 
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "nightly")]
+mod allocator;
+mod assert;
+#[cfg(feature = "bincode")]
+mod bincode;
+mod brand;
+mod builder;
+mod capability;
+mod cfg;
+#[cfg(feature = "unit_cmp")]
+mod cmp;
+mod coerce;
+mod compose;
+mod const_generic;
 mod contravariant;
+mod convert;
 mod covariant;
+mod cow;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "embedded-hal")]
+mod embedded_hal;
 mod end;
+#[cfg(feature = "std")]
+mod error;
+mod fmt;
+#[cfg(feature = "nightly")]
+mod fn_traits;
+#[cfg(feature = "frunk")]
+mod frunk;
+#[cfg(feature = "async")]
+mod future;
+mod hash;
+mod higher_kinded;
 mod inner;
 mod invariant;
+#[cfg(feature = "std")]
+mod io;
+mod iter;
+mod list;
+mod marker;
+mod mirror;
+mod negative;
+#[cfg(feature = "num-traits")]
+mod num;
+mod ops;
 #[cfg(test)]
+mod ops_tests;
+mod phantom_data_compat;
+mod phantom_fn;
+mod pin;
+#[cfg(feature = "postcard")]
+mod postcard;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "schemars")]
+mod schemars;
+pub mod sealed;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "nightly")]
+mod step;
+mod tag;
+mod task;
+mod termination;
+#[cfg(any(test, doctest))]
 mod tests;
+#[cfg(feature = "nightly")]
+mod try_trait;
+mod type_eq;
+#[cfg(feature = "typenum")]
+mod typenum;
 mod unused;
+mod variance;
+mod verify;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm_bindgen;
+mod with;
 
+pub use crate::brand::Brand;
+pub use crate::builder::UnusedBuilder;
+pub use crate::capability::Capability;
+pub use crate::coerce::{
+    coerce_contravariant, coerce_contravariant as lengthen_contravariant, coerce_covariant,
+    coerce_covariant as shorten_covariant, coerce_to, coerce_variance, CoerceTo,
+};
+pub use crate::compose::{Compose, ComposeVariance};
+#[doc(hidden)]
+pub use crate::const_generic::markers as __const;
+pub use crate::const_generic::UnusedConst;
 #[doc(hidden)]
 pub use crate::contravariant::Contravariant;
 #[doc(hidden)]
 pub use crate::covariant::Covariant;
+pub use crate::cow::UnusedCow;
 #[doc(hidden)]
 pub use crate::end::End;
+pub use crate::fmt::WriteSink;
+#[cfg(feature = "frunk")]
+pub use crate::frunk::{ContravariantHList, CovariantHList, HListUnused, UnusedHList};
+#[cfg(feature = "async")]
+pub use crate::future::{PendingForever, ReadyUnused};
+pub use crate::hash::NoopHasher;
+pub use crate::higher_kinded::{Apply, Functor, IdentityF, PairF, TypeConstructor, UnusedApply};
 #[doc(hidden)]
 pub use crate::invariant::Invariant;
+pub use crate::list::{
+    concat, head, tail, zip, PushBack, PushFront, UnusedConcat, UnusedHead, UnusedList, UnusedTail,
+    UnusedZip, Zip,
+};
+pub use crate::marker::UnusedMarker;
+pub use crate::mirror::Mirror;
+#[cfg(feature = "std")]
+pub use crate::mirror::TypeMap;
+pub use crate::negative::{NeitherSendNorSync, NonClone, NonCopy, NonSend, NonSync};
+pub use crate::ops::{merge, merge3, merge4, split, split3, split4};
+pub use crate::phantom_data_compat::{
+    from_phantom, from_phantom_variance, into_phantom, into_phantom_variance, PhantomVariance,
+};
+pub use crate::phantom_fn::{PhantomFn, PhantomFnMut, PhantomFnOnce};
+pub use crate::pin::{ForceUnpin, NotUnpin, PinnedUnused};
+pub use crate::tag::TypeTag;
+pub use crate::termination::Termination;
+pub use crate::type_eq::TypeEq;
+#[cfg(feature = "typenum")]
+pub use crate::typenum::{typenum_n, UnusedTypenumN};
 use crate::unused::UnusedImpl;
 #[doc(hidden)]
 pub use crate::unused::UnusedImpl::*;
+pub use crate::variance::{Variance, VarianceInfo, Variances};
+pub use crate::with::UnusedWith;
 
 /// A container for unused generic types.
 ///
@@ -135,9 +517,40 @@ pub use crate::unused::UnusedImpl::*;
 /// let _: Unused!(usize) = Unused;
 /// ```
 ///
+/// `Unused` is always zero-sized, with an alignment of 1. This can be checked
+/// with [`assert_zero_sized!`] and [`assert_align_one!`].
+///
 /// See the [crate documentation](crate) for more information.
 pub type Unused<T> = UnusedImpl<T>;
 
+/// An `Unused` that does not contain any types.
+///
+/// `Unused<End>` is the "empty phantom": it marks no types, so it places no
+/// requirements on auto traits or variance. Like
+/// [`Infallible`](core::convert::Infallible), [`End`] can never be created, but
+/// `PhantomBottom` can always be created as `Unused`.
+///
+/// ```
+/// use unused::{PhantomBottom, Unused};
+///
+/// let bottom: PhantomBottom = Unused;
+/// assert_eq!(core::mem::size_of_val(&bottom), 0);
+/// ```
+///
+/// `Infallible` can be converted to `PhantomBottom`, and, with the `nightly`
+/// feature, so can the never type, `!`.
+pub type PhantomBottom = Unused<End>;
+
+/// A [`PhantomBottom`], which can be used where a constant is needed.
+///
+/// ```
+/// use unused::{PhantomBottom, Unused, EMPTY};
+///
+/// static BOTTOM: PhantomBottom = EMPTY;
+/// assert_eq!(BOTTOM, Unused);
+/// ```
+pub const EMPTY: PhantomBottom = UnusedImpl::Unused;
+
 /// A macro that allows for the creation of [`type@Unused`] type containers.
 ///
 /// A basic example of usage can be found in the [crate documentation](crate).
@@ -201,10 +614,24 @@ pub type Unused<T> = UnusedImpl<T>;
 ///     foo
 /// }
 /// ```
+///
+/// Lifetimes can also be used directly, in which case `'a` is treated the same
+/// as `&'a ()`:
+///
+/// ```
+/// # use unused::Unused;
+/// struct Foo<'foo, T> {
+///     unused: Unused!('foo: covariant, T),
+/// }
+///
+/// fn change_foo_lifetime<'a, T>(foo: Foo<'static, T>) -> Foo<'a, T> {
+///     foo
+/// }
+/// ```
 #[macro_export]
 macro_rules! Unused {
-    ($($type:ty $(: $variance:ident)?),+ $(,)?) => {
-        $crate::Unused::<$crate::__impl_Unused!($($type $(:$variance)?,)+)>
+    ($($params:tt)+) => {
+        $crate::Unused::<$crate::__impl_Unused!($($params)+)>
     };
 }
 
@@ -214,16 +641,144 @@ macro_rules! __impl_Unused {
     () => {
         $crate::End
     };
-    ($type:ty, $($types:ty $(:$variances:ident)?,)*) => {
-        $crate::Invariant::<$type, $crate::__impl_Unused!($($types $(: $variances)?,)*)>
+    (@[] $type:ty; $($rest:tt)*) => {
+        $crate::Invariant::<$type, $crate::__impl_Unused!($($rest)*)>
+    };
+    (@[invariant] $type:ty; $($rest:tt)*) => {
+        $crate::Invariant::<$type, $crate::__impl_Unused!($($rest)*)>
+    };
+    (@[covariant] $type:ty; $($rest:tt)*) => {
+        $crate::Covariant::<$type, $crate::__impl_Unused!($($rest)*)>
+    };
+    (@[contravariant] $type:ty; $($rest:tt)*) => {
+        $crate::Contravariant::<$type, $crate::__impl_Unused!($($rest)*)>
+    };
+    ($lifetime:lifetime $(: $variance:ident)? $(, $($rest:tt)*)?) => {
+        $crate::__impl_Unused!(@[$($variance)?] &$lifetime (); $($($rest)*)?)
+    };
+    ($type:ty $(: $variance:ident)? $(, $($rest:tt)*)?) => {
+        $crate::__impl_Unused!(@[$($variance)?] $type; $($($rest)*)?)
     };
-    ($type:ty: invariant, $($types:ty $(: $variances:ident)?,)*) => {
-        $crate::Invariant::<$type, $crate::__impl_Unused!($($types $(: $variances)?,)*)>
+}
+
+/// A shorthand for an [`Unused!`] where every type is invariant.
+///
+/// ```
+/// use unused::{Unused, UnusedList};
+///
+/// let unused: UnusedList![u8, u16, u32] = Unused;
+/// // is the same as:
+/// let unused: Unused!(u8, u16, u32) = unused;
+/// ```
+///
+/// See also [`UnusedCovariantList!`] and [`UnusedContravariantList!`].
+#[macro_export]
+macro_rules! UnusedList {
+    ($($type:ty),+ $(,)?) => {
+        $crate::Unused!($($type: invariant),+)
+    };
+}
+
+/// A shorthand for an [`Unused!`] where every type is covariant.
+///
+/// ```
+/// use unused::{Unused, UnusedCovariantList};
+///
+/// let unused: UnusedCovariantList![u8, u16, u32] = Unused;
+/// // is the same as:
+/// let unused: Unused!(u8: covariant, u16: covariant, u32: covariant) = unused;
+/// ```
+#[macro_export]
+macro_rules! UnusedCovariantList {
+    ($($type:ty),+ $(,)?) => {
+        $crate::Unused!($($type: covariant),+)
+    };
+}
+
+/// A shorthand for an [`Unused!`] where every type is contravariant.
+///
+/// ```
+/// use unused::{Unused, UnusedContravariantList};
+///
+/// let unused: UnusedContravariantList![u8, u16, u32] = Unused;
+/// // is the same as:
+/// let unused: Unused!(u8: contravariant, u16: contravariant, u32: contravariant) = unused;
+/// ```
+#[macro_export]
+macro_rules! UnusedContravariantList {
+    ($($type:ty),+ $(,)?) => {
+        $crate::Unused!($($type: contravariant),+)
+    };
+}
+
+/// A macro that creates an [`type@Unused`] of const values.
+///
+/// Each value is a literal, a const generic parameter, or a block, and is a
+/// `usize` unless a type is given:
+///
+/// ```
+/// use unused::{Unused, UnusedConst};
+///
+/// struct Foo<const N: usize, const B: bool> {
+///     unused: UnusedConst!(N, B: bool),
+/// }
+///
+/// let unused: UnusedConst!(42usize, true: bool, 'a': char, { -1 }: i8) = Unused;
+/// ```
+///
+/// Any primitive integer type, `bool`, or `char` can be used.
+///
+/// See [`UnusedMixed!`] for mixing const values with types.
+#[macro_export]
+macro_rules! UnusedConst {
+    ($($value:tt $(: $type:ident)?),+ $(,)?) => {
+        $crate::Unused!($($crate::__impl_UnusedConst!($value $(: $type)?)),+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_UnusedConst {
+    ($value:tt) => {
+        $crate::__const::usize<$value>
+    };
+    ($value:tt : $type:ident) => {
+        $crate::__const::$type<$value>
+    };
+}
+
+/// A macro that creates an [`type@Unused`] of both types and const values.
+///
+/// Types are written as with [`Unused!`], and const values as with
+/// [`UnusedConst!`], prefixed with `const`:
+///
+/// ```
+/// use unused::Unused;
+///
+/// struct Foo<T, const N: usize, const B: bool> {
+///     unused: unused::UnusedMixed!(T: covariant, const N: usize, const B: bool),
+/// }
+/// ```
+#[macro_export]
+macro_rules! UnusedMixed {
+    ($($params:tt)+) => {
+        $crate::__impl_UnusedMixed!([] $($params)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_UnusedMixed {
+    ([$($done:tt)*]) => {
+        $crate::Unused!($($done)*)
     };
-    ($type:ty: covariant, $($types:ty $(: $variances:ident)?,)*) => {
-        $crate::Covariant::<$type, $crate::__impl_Unused!($($types $(: $variances)?,)*)>
+    ([$($done:tt)*] const $value:tt $(: $type:ident)? $(, $($rest:tt)*)?) => {
+        $crate::__impl_UnusedMixed!(
+            [$($done)* $crate::__impl_UnusedConst!($value $(: $type)?),]
+            $($($rest)*)?
+        )
     };
-    ($type:ty: contravariant, $($types:ty $(: $variances:ident)?,)*) => {
-        $crate::Contravariant::<$type, $crate::__impl_Unused!($($types $(: $variances)?,)*)>
+    ([$($done:tt)*] $param:tt $($rest:tt)*) => {
+        $crate::__impl_UnusedMixed!([$($done)* $param] $($rest)*)
     };
 }
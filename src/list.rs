@@ -0,0 +1,175 @@
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+use crate::{Contravariant, Covariant, End, Invariant};
+
+/// A non-empty chain of types in an `Unused`, which can be split into its
+/// first type and the remaining types.
+///
+/// This allows for type-level recursion over the types of an `Unused`. See
+/// [`head`], [`tail`], [`UnusedHead`], and [`UnusedTail`].
+///
+/// `UnusedList` is implemented for every chain with at least one type. The
+/// empty chain has no head or tail.
+///
+/// This trait is sealed.
+pub trait UnusedList: UnusedInner {
+    /// The first type, with its variance, as a chain of one type.
+    type Head: UnusedInner;
+
+    /// The remaining types.
+    type Tail: UnusedInner;
+}
+
+impl<T: ?Sized, N: UnusedInner> UnusedList for Invariant<T, N> {
+    type Head = Invariant<T, End>;
+    type Tail = N;
+}
+
+impl<T: ?Sized, N: UnusedInner> UnusedList for Covariant<T, N> {
+    type Head = Covariant<T, End>;
+    type Tail = N;
+}
+
+impl<T: ?Sized, N: UnusedInner> UnusedList for Contravariant<T, N> {
+    type Head = Contravariant<T, End>;
+    type Tail = N;
+}
+
+/// The first type of an [`UnusedList`].
+pub type UnusedHead<T> = <T as UnusedList>::Head;
+
+/// The types after the first type of an [`UnusedList`].
+pub type UnusedTail<T> = <T as UnusedList>::Tail;
+
+/// Returns an `Unused` of only the first type of an `Unused`.
+///
+/// ```
+/// use unused::Unused;
+///
+/// let unused: Unused!(u8: covariant, u16, u32) = Unused;
+/// let head: Unused!(u8: covariant) = unused::head(unused);
+/// ```
+pub fn head<T: UnusedList>(_unused: UnusedImpl<T>) -> UnusedImpl<T::Head> {
+    UnusedImpl::Unused
+}
+
+/// Returns an `Unused` of the types after the first type of an `Unused`.
+///
+/// ```
+/// use unused::Unused;
+///
+/// let unused: Unused!(u8: covariant, u16, u32) = Unused;
+/// let tail: Unused!(u16, u32) = unused::tail(unused);
+/// ```
+pub fn tail<T: UnusedList>(_unused: UnusedImpl<T>) -> UnusedImpl<T::Tail> {
+    UnusedImpl::Unused
+}
+
+/// A chain of types in an `Unused` that another chain can be appended to.
+///
+/// See [`concat()`].
+///
+/// This trait is sealed.
+pub trait UnusedConcat<U: UnusedInner>: UnusedInner {
+    /// The types of `Self`, followed by the types of `U`.
+    type Output: UnusedInner;
+}
+
+impl<U: UnusedInner> UnusedConcat<U> for End {
+    type Output = U;
+}
+
+impl<T: ?Sized, N: UnusedConcat<U>, U: UnusedInner> UnusedConcat<U> for Invariant<T, N> {
+    type Output = Invariant<T, N::Output>;
+}
+
+impl<T: ?Sized, N: UnusedConcat<U>, U: UnusedInner> UnusedConcat<U> for Covariant<T, N> {
+    type Output = Covariant<T, N::Output>;
+}
+
+impl<T: ?Sized, N: UnusedConcat<U>, U: UnusedInner> UnusedConcat<U> for Contravariant<T, N> {
+    type Output = Contravariant<T, N::Output>;
+}
+
+/// A chain with the types of `New` added before the types of `Chain`.
+///
+/// This is the same as `UnusedConcat<Chain>::Output` for `New`.
+pub type PushFront<New, Chain> = <New as UnusedConcat<Chain>>::Output;
+
+/// A chain with the types of `New` added after the types of `Chain`.
+///
+/// This is the same as `UnusedConcat<New>::Output` for `Chain`.
+pub type PushBack<New, Chain> = <Chain as UnusedConcat<New>>::Output;
+
+/// Combines two `Unused`s into a single `Unused` of the types of both.
+///
+/// ```
+/// use unused::Unused;
+///
+/// let a: Unused!(u8: covariant) = Unused;
+/// let b: Unused!(u16, u32: contravariant) = Unused;
+/// let ab: Unused!(u8: covariant, u16, u32: contravariant) = unused::concat(a, b);
+/// ```
+///
+/// Unlike [`merge`](crate::merge), the result is the same type as if all of
+/// the types had been listed in a single [`Unused!`](crate::Unused!).
+///
+/// This is not available through the `+` operator, as `Unused` already
+/// implements [`Add`](core::ops::Add) with itself.
+pub fn concat<T: UnusedConcat<U>, U: UnusedInner>(
+    _a: UnusedImpl<T>,
+    _b: UnusedImpl<U>,
+) -> UnusedImpl<T::Output> {
+    UnusedImpl::Unused
+}
+
+/// A chain of types in an `Unused` that can be interleaved with another
+/// chain.
+///
+/// See [`zip`].
+///
+/// This trait is sealed.
+pub trait UnusedZip<U: UnusedInner>: UnusedInner {
+    /// The types of `Self` and `U`, alternating between the two, starting
+    /// with `Self`.
+    type Output: UnusedInner;
+}
+
+impl<U: UnusedInner> UnusedZip<U> for End {
+    type Output = U;
+}
+
+impl<T: ?Sized, N: UnusedInner, U: UnusedZip<N>> UnusedZip<U> for Invariant<T, N> {
+    type Output = Invariant<T, U::Output>;
+}
+
+impl<T: ?Sized, N: UnusedInner, U: UnusedZip<N>> UnusedZip<U> for Covariant<T, N> {
+    type Output = Covariant<T, U::Output>;
+}
+
+impl<T: ?Sized, N: UnusedInner, U: UnusedZip<N>> UnusedZip<U> for Contravariant<T, N> {
+    type Output = Contravariant<T, U::Output>;
+}
+
+/// The types of two [`UnusedZip`]s, interleaved.
+pub type Zip<A, B> = <A as UnusedZip<B>>::Output;
+
+/// Combines two `Unused`s into a single `Unused` of the types of both,
+/// alternating between the two.
+///
+/// Each type keeps its variance. Once one `Unused` runs out of types, the
+/// remaining types of the other follow.
+///
+/// ```
+/// use unused::Unused;
+///
+/// let a: Unused!(u8: covariant, u16, u32) = Unused;
+/// let b: Unused!(i8: contravariant) = Unused;
+/// let ab: Unused!(u8: covariant, i8: contravariant, u16, u32) = unused::zip(a, b);
+/// ```
+pub fn zip<A: UnusedZip<B>, B: UnusedInner>(
+    _a: UnusedImpl<A>,
+    _b: UnusedImpl<B>,
+) -> UnusedImpl<Zip<A, B>> {
+    UnusedImpl::Unused
+}
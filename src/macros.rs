@@ -0,0 +1,64 @@
+/// Implements the same traits as `Unused` for a marker type, which must have
+/// a `new` function.
+///
+/// `@no_copy` leaves out `Copy`, and `@no_clone` leaves out both `Copy` and
+/// `Clone`.
+macro_rules! impl_marker {
+    ($name:ident) => {
+        impl<T: ?Sized> Clone for $name<T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T: ?Sized> Copy for $name<T> {}
+
+        impl_marker!(@no_clone $name);
+    };
+    (@no_copy $name:ident) => {
+        impl<T: ?Sized> Clone for $name<T> {
+            fn clone(&self) -> Self {
+                Self::new()
+            }
+        }
+
+        impl_marker!(@no_clone $name);
+    };
+    (@no_clone $name:ident) => {
+        impl<T: ?Sized> core::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($name)).finish()
+            }
+        }
+
+        impl<T: ?Sized> Default for $name<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T: ?Sized> PartialEq for $name<T> {
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+
+        impl<T: ?Sized> Eq for $name<T> {}
+
+        impl<T: ?Sized> PartialOrd for $name<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T: ?Sized> Ord for $name<T> {
+            fn cmp(&self, _other: &Self) -> core::cmp::Ordering {
+                core::cmp::Ordering::Equal
+            }
+        }
+
+        impl<T: ?Sized> core::hash::Hash for $name<T> {
+            fn hash<H: core::hash::Hasher>(&self, _state: &mut H) {}
+        }
+    };
+}
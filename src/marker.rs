@@ -0,0 +1,53 @@
+use core::fmt;
+
+use crate::inner::UnusedInner;
+use crate::UnusedImpl;
+
+/// An object-safe trait implemented by [`Unused`](type@crate::Unused) and the
+/// types that it can contain.
+///
+/// Unlike the types it is implemented for, `UnusedMarker` can be used as a
+/// trait object, such as in a `Box<dyn UnusedMarker>`.
+///
+/// ```
+/// use unused::{Unused, UnusedMarker};
+///
+/// let markers: Vec<Box<dyn UnusedMarker>> = vec![
+///     Box::new(<Unused!(u8)>::new()),
+///     Box::new(<Unused!(u8, u16: covariant)>::new()),
+/// ];
+///
+/// assert_eq!(format!("{:#?}", markers[1]), "Unused(invariant, covariant)");
+/// assert_eq!(markers[1].arity(), 2);
+/// ```
+///
+/// This trait is sealed.
+pub trait UnusedMarker: fmt::Debug + sealed::Sealed {
+    /// Returns the number of types in the chain.
+    ///
+    /// This is the same as [`Unused::ARITY`](type@crate::Unused).
+    fn arity(&self) -> usize;
+}
+
+impl<T: UnusedInner + fmt::Debug> UnusedMarker for T {
+    fn arity(&self) -> usize {
+        T::ARITY
+    }
+}
+
+impl<T: UnusedInner> UnusedMarker for UnusedImpl<T> {
+    fn arity(&self) -> usize {
+        T::ARITY
+    }
+}
+
+mod sealed {
+    use crate::inner::UnusedInner;
+    use crate::UnusedImpl;
+
+    pub trait Sealed {}
+
+    impl<T: UnusedInner> Sealed for T {}
+
+    impl<T: UnusedInner> Sealed for UnusedImpl<T> {}
+}
@@ -0,0 +1,58 @@
+#[cfg(feature = "std")]
+use std::any::{Any, TypeId};
+#[cfg(feature = "std")]
+use std::boxed::Box;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::Unused;
+
+/// A zero-sized witness for the type `T`, which is invariant over `T`.
+///
+/// A `Mirror<T>` stands in for the type `T`. It is zero-sized, and the
+/// `TypeId` of `Mirror<T>` is different for every `T`, so, with the `std`
+/// feature, it can be stored in a [`TypeMap`] without any allocation:
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use unused::{Mirror, TypeMap};
+///
+/// let mut map = TypeMap::new();
+/// Mirror::<u8>::insert(&mut map);
+///
+/// assert_eq!(Mirror::<u8>::lookup(&map), Some(&Mirror::new()));
+/// assert_eq!(Mirror::<u16>::lookup(&map), None);
+/// # }
+/// ```
+pub struct Mirror<T: ?Sized> {
+    _unused: Unused!(T: invariant),
+}
+
+impl<T: ?Sized> Mirror<T> {
+    /// Creates a new `Mirror`.
+    pub const fn new() -> Self {
+        Self { _unused: Unused }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized + 'static> Mirror<T> {
+    /// Inserts the `Mirror` for `T` into a [`TypeMap`], returning whether it
+    /// was not already present.
+    pub fn insert(map: &mut TypeMap) -> bool {
+        map.insert(TypeId::of::<Self>(), Box::new(Self::new()))
+            .is_none()
+    }
+
+    /// Looks up the `Mirror` for `T` in a [`TypeMap`].
+    pub fn lookup(map: &TypeMap) -> Option<&Self> {
+        map.get(&TypeId::of::<Self>())?.downcast_ref()
+    }
+}
+
+impl_marker!(Mirror);
+
+/// A map from types to values, such as [`Mirror`]s.
+#[cfg(feature = "std")]
+pub type TypeMap = HashMap<TypeId, Box<dyn Any>>;
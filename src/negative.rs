@@ -0,0 +1,146 @@
+use core::marker::PhantomData;
+
+use crate::Unused;
+
+/// A zero-sized marker for an unused `T` that is never [`Send`], but is
+/// always [`Sync`].
+///
+/// ```compile_fail
+/// use unused::NonSend;
+///
+/// fn is_send<T: Send>() {}
+///
+/// is_send::<NonSend<u8>>();
+/// ```
+pub struct NonSend<T: ?Sized> {
+    _unused: Unused!(T),
+    _marker: PhantomData<*const ()>,
+}
+
+// SAFETY: `NonSend` does not contain any data.
+unsafe impl<T: ?Sized> Sync for NonSend<T> {}
+
+impl<T: ?Sized> NonSend<T> {
+    /// Creates a new `NonSend`.
+    pub const fn new() -> Self {
+        Self {
+            _unused: Unused,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl_marker!(NonSend);
+
+/// A zero-sized marker for an unused `T` that is never [`Sync`], but is
+/// always [`Send`].
+///
+/// ```compile_fail
+/// use unused::NonSync;
+///
+/// fn is_sync<T: Sync>() {}
+///
+/// is_sync::<NonSync<u8>>();
+/// ```
+pub struct NonSync<T: ?Sized> {
+    _unused: Unused!(T),
+    _marker: PhantomData<*const ()>,
+}
+
+// SAFETY: `NonSync` does not contain any data.
+unsafe impl<T: ?Sized> Send for NonSync<T> {}
+
+impl<T: ?Sized> NonSync<T> {
+    /// Creates a new `NonSync`.
+    pub const fn new() -> Self {
+        Self {
+            _unused: Unused,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl_marker!(NonSync);
+
+/// A zero-sized marker for an unused `T` that is never [`Send`] or [`Sync`].
+///
+/// ```compile_fail
+/// use unused::NeitherSendNorSync;
+///
+/// fn is_send<T: Send>() {}
+///
+/// is_send::<NeitherSendNorSync<u8>>();
+/// ```
+///
+/// ```compile_fail
+/// use unused::NeitherSendNorSync;
+///
+/// fn is_sync<T: Sync>() {}
+///
+/// is_sync::<NeitherSendNorSync<u8>>();
+/// ```
+pub struct NeitherSendNorSync<T: ?Sized> {
+    _unused: Unused!(T),
+    _marker: PhantomData<*const ()>,
+}
+
+impl<T: ?Sized> NeitherSendNorSync<T> {
+    /// Creates a new `NeitherSendNorSync`.
+    pub const fn new() -> Self {
+        Self {
+            _unused: Unused,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl_marker!(NeitherSendNorSync);
+
+/// A zero-sized marker for an unused `T` that is never [`Copy`], but is
+/// always [`Clone`], [`Send`], and [`Sync`].
+///
+/// This is useful for making a type that would otherwise be `Copy` only
+/// movable.
+///
+/// ```compile_fail
+/// use unused::NonCopy;
+///
+/// fn is_copy<T: Copy>() {}
+///
+/// is_copy::<NonCopy<u8>>();
+/// ```
+pub struct NonCopy<T: ?Sized> {
+    _unused: Unused!(T),
+}
+
+impl<T: ?Sized> NonCopy<T> {
+    /// Creates a new `NonCopy`.
+    pub const fn new() -> Self {
+        Self { _unused: Unused }
+    }
+}
+
+impl_marker!(@no_copy NonCopy);
+
+/// A zero-sized marker for an unused `T` that is never [`Clone`] or [`Copy`],
+/// but is always [`Send`] and [`Sync`].
+///
+/// ```compile_fail
+/// use unused::NonClone;
+///
+/// fn is_clone<T: Clone>() {}
+///
+/// is_clone::<NonClone<u8>>();
+/// ```
+pub struct NonClone<T: ?Sized> {
+    _unused: Unused!(T),
+}
+
+impl<T: ?Sized> NonClone<T> {
+    /// Creates a new `NonClone`.
+    pub const fn new() -> Self {
+        Self { _unused: Unused }
+    }
+}
+
+impl_marker!(@no_clone NonClone);
@@ -0,0 +1,35 @@
+use core::convert::Infallible;
+
+use num_traits::{Num, One, Zero};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+impl<T: UnusedInner> Zero for UnusedImpl<T> {
+    fn zero() -> Self {
+        Self::Unused
+    }
+
+    fn is_zero(&self) -> bool {
+        true
+    }
+}
+
+impl<T: UnusedInner> One for UnusedImpl<T> {
+    fn one() -> Self {
+        Self::Unused
+    }
+
+    fn is_one(&self) -> bool {
+        true
+    }
+}
+
+/// Every string, in every radix, is parsed as `Unused`.
+impl<T: UnusedInner> Num for UnusedImpl<T> {
+    type FromStrRadixErr = Infallible;
+
+    fn from_str_radix(_str: &str, _radix: u32) -> Result<Self, Infallible> {
+        Ok(Self::Unused)
+    }
+}
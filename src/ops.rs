@@ -0,0 +1,178 @@
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, BitAnd, BitOr, BitXor, Bound, Div, Index, Mul, Neg, Not, RangeBounds, Rem, Shl, Shr, Sub,
+};
+use core::slice::SliceIndex;
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+macro_rules! impl_binary_ops {
+    ($($trait:ident $method:ident),* $(,)?) => {
+        $(
+            impl<T: UnusedInner> $trait for UnusedImpl<T> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $method(self, _rhs: Self) -> Self {
+                    Self::Unused
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_unary_ops {
+    ($($trait:ident $method:ident),* $(,)?) => {
+        $(
+            impl<T: UnusedInner> $trait for UnusedImpl<T> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $method(self) -> Self {
+                    Self::Unused
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_ops!(
+    Add add,
+    Sub sub,
+    Mul mul,
+    Div div,
+    Rem rem,
+    BitAnd bitand,
+    BitOr bitor,
+    BitXor bitxor,
+    Shl shl,
+    Shr shr,
+);
+
+impl_unary_ops!(Neg neg, Not not);
+
+/// Summing any number of `Unused`s adds them all together, which yields
+/// `Unused`.
+impl<T: UnusedInner> Sum for UnusedImpl<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::Unused, Add::add)
+    }
+}
+
+impl<'a, T: UnusedInner> Sum<&'a UnusedImpl<T>> for UnusedImpl<T> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplying any number of `Unused`s together yields `Unused`.
+impl<T: UnusedInner> Product for UnusedImpl<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::Unused, Mul::mul)
+    }
+}
+
+impl<'a, T: UnusedInner> Product<&'a UnusedImpl<T>> for UnusedImpl<T> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+/// `Unused` is a range with no bounds.
+impl<T: UnusedInner> RangeBounds<()> for UnusedImpl<T> {
+    fn start_bound(&self) -> Bound<&()> {
+        Bound::Unbounded
+    }
+
+    fn end_bound(&self) -> Bound<&()> {
+        Bound::Unbounded
+    }
+}
+
+const EMPTY: &[()] = &[];
+
+/// `Unused` is indexed as an empty slice, so indexing with `..` yields an
+/// empty slice, and indexing with a `usize` always panics.
+impl<T: UnusedInner, I: SliceIndex<[()]>> Index<I> for UnusedImpl<T> {
+    type Output = I::Output;
+
+    #[track_caller]
+    fn index(&self, index: I) -> &I::Output {
+        &EMPTY[index]
+    }
+}
+
+/// Combines two `Unused`s into a single `Unused` of a tuple.
+///
+/// The resulting `Unused` keeps the variances of both `Unused`s.
+///
+/// ```
+/// use unused::Unused;
+///
+/// let a: Unused!(u8: covariant) = Unused;
+/// let b: Unused!(u16: contravariant) = Unused;
+/// let ab = unused::merge(a, b);
+/// let (a, b) = unused::split(ab);
+/// ```
+pub fn merge<A: UnusedInner, B: UnusedInner>(
+    _a: UnusedImpl<A>,
+    _b: UnusedImpl<B>,
+) -> UnusedImpl<(A, B)> {
+    UnusedImpl::Unused
+}
+
+/// Splits an `Unused` of a tuple into two `Unused`s.
+///
+/// This is the inverse of [`merge`].
+pub fn split<A: UnusedInner, B: UnusedInner>(
+    _ab: UnusedImpl<(A, B)>,
+) -> (UnusedImpl<A>, UnusedImpl<B>) {
+    (UnusedImpl::Unused, UnusedImpl::Unused)
+}
+
+/// Combines three `Unused`s into a single `Unused` of a tuple.
+///
+/// See [`merge`].
+pub fn merge3<A: UnusedInner, B: UnusedInner, C: UnusedInner>(
+    _a: UnusedImpl<A>,
+    _b: UnusedImpl<B>,
+    _c: UnusedImpl<C>,
+) -> UnusedImpl<(A, B, C)> {
+    UnusedImpl::Unused
+}
+
+/// Splits an `Unused` of a tuple into three `Unused`s.
+///
+/// This is the inverse of [`merge3`].
+pub fn split3<A: UnusedInner, B: UnusedInner, C: UnusedInner>(
+    _abc: UnusedImpl<(A, B, C)>,
+) -> (UnusedImpl<A>, UnusedImpl<B>, UnusedImpl<C>) {
+    (UnusedImpl::Unused, UnusedImpl::Unused, UnusedImpl::Unused)
+}
+
+/// Combines four `Unused`s into a single `Unused` of a tuple.
+///
+/// See [`merge`].
+pub fn merge4<A: UnusedInner, B: UnusedInner, C: UnusedInner, D: UnusedInner>(
+    _a: UnusedImpl<A>,
+    _b: UnusedImpl<B>,
+    _c: UnusedImpl<C>,
+    _d: UnusedImpl<D>,
+) -> UnusedImpl<(A, B, C, D)> {
+    UnusedImpl::Unused
+}
+
+/// Splits an `Unused` of a tuple into four `Unused`s.
+///
+/// This is the inverse of [`merge4`].
+pub fn split4<A: UnusedInner, B: UnusedInner, C: UnusedInner, D: UnusedInner>(
+    _abcd: UnusedImpl<(A, B, C, D)>,
+) -> (UnusedImpl<A>, UnusedImpl<B>, UnusedImpl<C>, UnusedImpl<D>) {
+    (
+        UnusedImpl::Unused,
+        UnusedImpl::Unused,
+        UnusedImpl::Unused,
+        UnusedImpl::Unused,
+    )
+}
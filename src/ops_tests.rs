@@ -0,0 +1,60 @@
+use core::cell::Cell;
+
+use crate::Unused;
+
+/// Counts the items taken from `iter`.
+struct Counting<'a, I> {
+    iter: I,
+    count: &'a Cell<usize>,
+}
+
+impl<I: Iterator> Iterator for Counting<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+        self.count.set(self.count.get() + 1);
+        Some(item)
+    }
+}
+
+/// `Sum` and `Product` take every item from the iterator, even though the
+/// result is always `Unused`, so any side effects of the iterator happen.
+#[test]
+fn sum_and_product_consume_the_iterator() {
+    let unused: Unused!(u8) = Unused;
+    let values = [unused; 5];
+    let count = Cell::new(0);
+    let counting = || Counting {
+        iter: values.iter(),
+        count: &count,
+    };
+
+    assert_eq!(counting().copied().sum::<Unused!(u8)>(), Unused);
+    assert_eq!(count.replace(0), 5);
+    assert_eq!(counting().sum::<Unused!(u8)>(), Unused);
+    assert_eq!(count.replace(0), 5);
+    assert_eq!(counting().copied().product::<Unused!(u8)>(), Unused);
+    assert_eq!(count.replace(0), 5);
+    assert_eq!(counting().product::<Unused!(u8)>(), Unused);
+    assert_eq!(count.replace(0), 5);
+}
+
+/// A partly consumed iterator is consumed to the end.
+#[test]
+fn sum_and_product_consume_the_rest() {
+    let unused: Unused!(u8) = Unused;
+    let values = [unused; 5];
+    let count = Cell::new(0);
+    let mut counting = Counting {
+        iter: values.iter(),
+        count: &count,
+    };
+
+    counting.next();
+    assert_eq!(counting.by_ref().sum::<Unused!(u8)>(), Unused);
+    assert_eq!(count.get(), 5);
+    assert_eq!(counting.next(), None);
+    assert_eq!(counting.product::<Unused!(u8)>(), Unused);
+    assert_eq!(count.get(), 5);
+}
@@ -0,0 +1,138 @@
+use core::marker::PhantomData;
+
+use crate::contravariant::Contravariant;
+use crate::covariant::Covariant;
+use crate::end::End;
+use crate::inner::UnusedInner;
+use crate::invariant::Invariant;
+use crate::unused::UnusedImpl;
+
+/// Converts an `Unused` into a [`PhantomData`] with the same variances.
+///
+/// This is the same as `Unused::phantom`, as a free function. Both are
+/// zero-sized, so no data is converted.
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use unused::Unused;
+///
+/// fn send_sync<T: Send + Sync>(_: T) {}
+///
+/// let unused: Unused!(Rc<String>) = Unused;
+/// send_sync(unused::into_phantom(unused));
+/// ```
+///
+/// The returned `PhantomData` is over the types of the `Unused`, with their
+/// variances, rather than over the unused types themselves. Like the
+/// `Unused`, it is always [`Send`] and [`Sync`], unlike a `PhantomData<X>`,
+/// which is only `Send` and `Sync` if `X` is.
+pub const fn into_phantom<T: UnusedInner>(_unused: UnusedImpl<T>) -> PhantomData<T> {
+    PhantomData
+}
+
+/// Converts a [`PhantomData`] returned by [`into_phantom`] back into an
+/// `Unused`.
+///
+/// This is the same as the `From<PhantomData<T>>` implementation of `Unused`,
+/// but can be used in `const` contexts.
+///
+/// ```
+/// use unused::Unused;
+///
+/// const UNUSED: Unused!(String: covariant) =
+///     unused::from_phantom(unused::into_phantom(Unused));
+/// ```
+pub const fn from_phantom<T: UnusedInner>(_phantom: PhantomData<T>) -> UnusedImpl<T> {
+    UnusedImpl::Unused
+}
+
+/// A single type with a variance, which has a [`PhantomData`] that is usually
+/// written for that variance.
+///
+/// | `Unused`                    | `PhantomData`               |
+/// |-----------------------------|-----------------------------|
+/// | `Unused!(T: invariant)`     | `PhantomData<fn(T) -> T>`   |
+/// | `Unused!(T: covariant)`     | `PhantomData<T>`            |
+/// | `Unused!(T: contravariant)` | `PhantomData<fn(T)>`        |
+///
+/// This trait is sealed.
+pub trait PhantomVariance: UnusedInner {
+    /// The `PhantomData` with the same variance.
+    type Phantom: Copy;
+
+    #[doc(hidden)]
+    const PHANTOM: Self::Phantom;
+}
+
+/// `PhantomData<fn(T) -> T>` is invariant over `T`, as `T` appears both as an
+/// argument, which is contravariant, and as a return type, which is covariant.
+impl<T> PhantomVariance for Invariant<T, End> {
+    type Phantom = PhantomData<fn(T) -> T>;
+
+    const PHANTOM: Self::Phantom = PhantomData;
+}
+
+/// `PhantomData<T>` is covariant over `T`, as if it owned a `T`. Unlike
+/// `Unused`, it also makes the containing type only [`Send`] and [`Sync`] if
+/// `T` is, and tells the drop checker that a `T` may be dropped.
+impl<T: ?Sized> PhantomVariance for Covariant<T, End> {
+    type Phantom = PhantomData<T>;
+
+    const PHANTOM: Self::Phantom = PhantomData;
+}
+
+/// `PhantomData<fn(T)>` is contravariant over `T`, as `T` only appears as an
+/// argument.
+impl<T> PhantomVariance for Contravariant<T, End> {
+    type Phantom = PhantomData<fn(T)>;
+
+    const PHANTOM: Self::Phantom = PhantomData;
+}
+
+/// Converts an `Unused` for a single type into the [`PhantomData`] that is
+/// usually written for its variance.
+///
+/// ```
+/// use core::marker::PhantomData;
+///
+/// use unused::Unused;
+///
+/// let unused: Unused!(u8: invariant) = Unused;
+/// let _: PhantomData<fn(u8) -> u8> = unused::into_phantom_variance(unused);
+///
+/// let unused: Unused!(str: covariant) = Unused;
+/// let _: PhantomData<str> = unused::into_phantom_variance(unused);
+///
+/// let unused: Unused!(u8: contravariant) = Unused;
+/// let _: PhantomData<fn(u8)> = unused::into_phantom_variance(unused);
+/// ```
+///
+/// See [`PhantomVariance`] for the `PhantomData` of each variance. Unlike
+/// [`into_phantom`] and the `From` implementation, which convert into a
+/// `PhantomData` over the types of the `Unused`, this converts into a
+/// `PhantomData` over the type itself.
+pub const fn into_phantom_variance<T: PhantomVariance>(_unused: UnusedImpl<T>) -> T::Phantom {
+    T::PHANTOM
+}
+
+/// Converts the [`PhantomData`] that is usually written for a variance into
+/// an `Unused` for a single type.
+///
+/// The variance cannot be inferred from the `PhantomData`, so the type of the
+/// `Unused` has to be known:
+///
+/// ```
+/// use core::marker::PhantomData;
+///
+/// use unused::Unused;
+///
+/// let _: Unused!(u8: invariant) = unused::from_phantom_variance(PhantomData::<fn(u8) -> u8>);
+/// let _: Unused!(str: covariant) = unused::from_phantom_variance(PhantomData::<str>);
+/// let _: Unused!(u8: contravariant) = unused::from_phantom_variance(PhantomData::<fn(u8)>);
+/// ```
+///
+/// See [`PhantomVariance`] for the `PhantomData` of each variance.
+pub const fn from_phantom_variance<T: PhantomVariance>(_phantom: T::Phantom) -> UnusedImpl<T> {
+    UnusedImpl::Unused
+}
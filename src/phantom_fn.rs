@@ -0,0 +1,35 @@
+use crate::Unused;
+
+/// An `Unused` that acts like it contains a function from `Args` to `Ret`,
+/// for variance purposes.
+///
+/// Like `PhantomData<fn(Args) -> Ret>`, it is contravariant over `Args` and
+/// covariant over `Ret`, but the variances are spelled out, and it is always
+/// [`Send`] and [`Sync`].
+///
+/// ```
+/// use unused::PhantomFn;
+///
+/// struct Parser<Input, Output> {
+///     unused: PhantomFn<Input, Output>,
+/// }
+///
+/// fn parser<'a>(parser: Parser<&'a str, &'static str>) -> Parser<&'static str, &'a str> {
+///     parser
+/// }
+/// ```
+pub type PhantomFn<Args, Ret> = Unused!(Args: contravariant, Ret: covariant);
+
+/// An `Unused` that acts like it contains an [`FnMut`] from `Args` to `Ret`,
+/// for variance purposes.
+///
+/// It is invariant over both `Args` and `Ret`, as a function that is called
+/// repeatedly is often used to both read and write the state of its caller.
+pub type PhantomFnMut<Args, Ret> = Unused!(Args: invariant, Ret: invariant);
+
+/// An `Unused` that acts like it contains an [`FnOnce`] from `Args` to `Ret`,
+/// for variance purposes.
+///
+/// It is invariant over `Args` and covariant over `Ret`, as the function only
+/// ever produces a single `Ret`.
+pub type PhantomFnOnce<Args, Ret> = Unused!(Args: invariant, Ret: covariant);
@@ -0,0 +1,63 @@
+use core::marker::{PhantomData, PhantomPinned};
+
+use crate::Unused;
+
+/// A zero-sized marker for an unused `T` that is never [`Unpin`], but is
+/// always [`Send`] and [`Sync`].
+///
+/// This is similar to [`PhantomPinned`].
+///
+/// ```compile_fail
+/// use unused::NotUnpin;
+///
+/// fn is_unpin<T: Unpin>() {}
+///
+/// is_unpin::<NotUnpin<u8>>();
+/// ```
+pub struct NotUnpin<T: ?Sized> {
+    _unused: Unused!(T),
+    _marker: PhantomData<PhantomPinned>,
+}
+
+impl<T: ?Sized> NotUnpin<T> {
+    /// Creates a new `NotUnpin`.
+    pub const fn new() -> Self {
+        Self {
+            _unused: Unused,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl_marker!(NotUnpin);
+
+/// An alias for [`NotUnpin`], a zero-sized `Unused` that is never [`Unpin`].
+///
+/// ```compile_fail
+/// use unused::PinnedUnused;
+///
+/// fn is_unpin<T: Unpin>() {}
+///
+/// is_unpin::<PinnedUnused<u8>>();
+/// ```
+pub type PinnedUnused<T> = NotUnpin<T>;
+
+/// A zero-sized marker for an unused `T` that is always [`Unpin`], [`Send`],
+/// and [`Sync`].
+///
+/// This behaves the same as [`Unused`](type@crate::Unused), but makes the
+/// intent of being `Unpin` explicit.
+pub struct ForceUnpin<T: ?Sized> {
+    _unused: Unused!(T),
+}
+
+impl<T: ?Sized> ForceUnpin<T> {
+    /// Creates a new `ForceUnpin`.
+    pub const fn new() -> Self {
+        Self { _unused: Unused }
+    }
+}
+
+impl<T: ?Sized> Unpin for ForceUnpin<T> {}
+
+impl_marker!(ForceUnpin);
@@ -0,0 +1,9 @@
+use postcard::experimental::max_size::MaxSize;
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// `Unused` is serialized by `postcard` as zero bytes.
+impl<T: UnusedInner> MaxSize for UnusedImpl<T> {
+    const POSTCARD_MAX_SIZE: usize = 0;
+}
@@ -0,0 +1,39 @@
+use core::convert::Infallible;
+
+use rayon::iter::{Empty, IntoParallelIterator};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// `Unused` is iterated over in parallel as an [`Empty`](rayon::iter::Empty),
+/// which never yields anything.
+///
+/// `Unused` is not a `ParallelIterator` itself, as the methods of
+/// `ParallelIterator` and `IndexedParallelIterator` would shadow those of
+/// traits such as [`PartialEq`] and [`Ord`] when they are in scope.
+impl<T: UnusedInner> IntoParallelIterator for UnusedImpl<T> {
+    type Iter = Empty<Infallible>;
+    type Item = Infallible;
+
+    fn into_par_iter(self) -> Empty<Infallible> {
+        rayon::iter::empty()
+    }
+}
+
+impl<T: UnusedInner> IntoParallelIterator for &UnusedImpl<T> {
+    type Iter = Empty<Infallible>;
+    type Item = Infallible;
+
+    fn into_par_iter(self) -> Empty<Infallible> {
+        rayon::iter::empty()
+    }
+}
+
+impl<T: UnusedInner> IntoParallelIterator for &mut UnusedImpl<T> {
+    type Iter = Empty<Infallible>;
+    type Item = Infallible;
+
+    fn into_par_iter(self) -> Empty<Infallible> {
+        rayon::iter::empty()
+    }
+}
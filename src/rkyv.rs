@@ -0,0 +1,43 @@
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::Fallible;
+use rkyv::traits::CopyOptimization;
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+// SAFETY: `Unused` always has a size of 0 and an align of 1, and so has a
+// stable, well-defined layout that is the same on all targets.
+unsafe impl<T: UnusedInner> Portable for UnusedImpl<T> {}
+
+// SAFETY: `Unused` is zero-sized and only has one value, so there are no
+// bytes to check.
+unsafe impl<T: UnusedInner, C: Fallible + ?Sized> CheckBytes<C> for UnusedImpl<T> {
+    #[inline]
+    unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+/// `Unused` is archived as itself, which takes up no space.
+impl<T: UnusedInner> Archive for UnusedImpl<T> {
+    // SAFETY: `Unused` is zero-sized, so copying it copies no bytes.
+    const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe { CopyOptimization::enable() };
+
+    type Archived = Self;
+    type Resolver = ();
+
+    fn resolve(&self, _: Self::Resolver, _: Place<Self::Archived>) {}
+}
+
+impl<T: UnusedInner, S: Fallible + ?Sized> Serialize<S> for UnusedImpl<T> {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<T: UnusedInner, D: Fallible + ?Sized> Deserialize<UnusedImpl<T>, D> for UnusedImpl<T> {
+    fn deserialize(&self, _: &mut D) -> Result<UnusedImpl<T>, D::Error> {
+        Ok(Self::Unused)
+    }
+}
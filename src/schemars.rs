@@ -0,0 +1,27 @@
+extern crate alloc;
+
+use alloc::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// `Unused` has the same schema as `()`, as it is always serialized as a unit.
+impl<T: UnusedInner> JsonSchema for UnusedImpl<T> {
+    fn inline_schema() -> bool {
+        <()>::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        <()>::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        <()>::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        <()>::json_schema(generator)
+    }
+}
@@ -0,0 +1,192 @@
+//! Helpers for sealing traits, so that they cannot be implemented outside of
+//! the crate that defines them.
+//!
+//! A trait is sealed by requiring [`HasSealedToken`] with a token type that
+//! only its crate can name. The token type is declared with
+//! [`sealed_token!`](crate::sealed_token!), which takes care of the private
+//! module that the sealed trait pattern normally requires. Types are then
+//! sealed by implementing [`SealedToken`] for the token:
+//!
+//! ```
+//! mod library {
+//!     use unused::sealed::{HasSealedToken, SealedToken};
+//!
+//!     unused::sealed_token!(Seal);
+//!
+//!     pub trait Shape: HasSealedToken<Seal> {
+//!         fn sides(&self) -> u32;
+//!     }
+//!
+//!     pub struct Square;
+//!
+//!     impl SealedToken<Square> for Seal {}
+//!
+//!     impl Shape for Square {
+//!         fn sides(&self) -> u32 {
+//!             4
+//!         }
+//!     }
+//! }
+//!
+//! use library::Shape;
+//!
+//! assert_eq!(library::Square.sides(), 4);
+//! ```
+//!
+//! Code outside of `library` cannot name `Seal`, so it cannot implement
+//! `Shape`:
+//!
+//! ```compile_fail
+//! mod library {
+//!     unused::sealed_token!(Seal);
+//!
+//!     pub trait Shape: unused::sealed::HasSealedToken<Seal> {}
+//! }
+//!
+//! struct Triangle;
+//!
+//! impl unused::sealed::SealedToken<Triangle> for library::Seal {}
+//!
+//! impl library::Shape for Triangle {}
+//! ```
+//!
+//! [`HasSealedToken`] cannot be implemented for every token, as it is already
+//! implemented for every type that a token seals:
+//!
+//! ```compile_fail
+//! mod library {
+//!     unused::sealed_token!(Seal);
+//!
+//!     pub trait Shape: unused::sealed::HasSealedToken<Seal> {}
+//! }
+//!
+//! struct Triangle;
+//!
+//! impl<T: ?Sized> unused::sealed::HasSealedToken<T> for Triangle {}
+//!
+//! impl library::Shape for Triangle {}
+//! ```
+//!
+//! Neither can [`SealedToken`] be implemented by every token, as only the
+//! crate that owns a token may implement it:
+//!
+//! ```compile_fail
+//! mod library {
+//!     unused::sealed_token!(Seal);
+//!
+//!     pub trait Shape: unused::sealed::HasSealedToken<Seal> {}
+//! }
+//!
+//! struct Triangle;
+//!
+//! impl<T: ?Sized> unused::sealed::SealedToken<Triangle> for T {}
+//!
+//! impl library::Shape for Triangle {}
+//! ```
+
+use crate::Unused;
+
+/// The seal for a sealed trait, which is implemented for `T` if `Token`
+/// seals it with [`SealedToken`].
+///
+/// `HasSealedToken` is implemented for every such `T` and `Token`, so it
+/// cannot be implemented by any other crate.
+///
+/// See the [module documentation](self) for more information.
+pub trait HasSealedToken<Token: ?Sized> {}
+
+impl<T: ?Sized, Token: ?Sized + SealedToken<T>> HasSealedToken<Token> for T {}
+
+/// Implemented for a token by the crate that owns it, for each `T` that it
+/// seals.
+///
+/// Only the crate that owns a token can implement `SealedToken` for it.
+///
+/// See the [module documentation](self) for more information.
+pub trait SealedToken<T: ?Sized> {}
+
+/// A zero-sized proof that a sealed method is being called by the crate that
+/// owns `Token`.
+///
+/// A `Sealed` can only be created with a value of `Token`, so taking one as an
+/// argument prevents a method of a sealed trait from being called by other
+/// crates. `Sealed` contains an [`Unused`](type@crate::Unused), so it does not
+/// own a `T` or a `Token`.
+///
+/// ```
+/// mod library {
+///     use unused::sealed::{HasSealedToken, Sealed, SealedToken};
+///
+///     unused::sealed_token!(Seal);
+///
+///     pub trait Shape: HasSealedToken<Seal> {
+///         #[doc(hidden)]
+///         fn sides_internal(&self, sealed: Sealed<Self, Seal>) -> u32;
+///     }
+///
+///     pub struct Square;
+///
+///     impl SealedToken<Square> for Seal {}
+///
+///     impl Shape for Square {
+///         fn sides_internal(&self, _sealed: Sealed<Self, Seal>) -> u32 {
+///             4
+///         }
+///     }
+///
+///     pub fn sides<S: Shape>(shape: &S) -> u32 {
+///         shape.sides_internal(Sealed::new(Seal))
+///     }
+/// }
+///
+/// assert_eq!(library::sides(&library::Square), 4);
+/// ```
+pub struct Sealed<T: ?Sized, Token: ?Sized> {
+    _unused: Unused!(T, Token),
+}
+
+impl<T: ?Sized, Token> Sealed<T, Token> {
+    /// Creates a new `Sealed` with the given token.
+    pub fn new(_token: Token) -> Self {
+        Self { _unused: Unused }
+    }
+}
+
+impl<T: ?Sized, Token: ?Sized> Clone for Sealed<T, Token> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, Token: ?Sized> Copy for Sealed<T, Token> {}
+
+impl<T: ?Sized, Token: ?Sized> core::fmt::Debug for Sealed<T, Token> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Sealed").finish()
+    }
+}
+
+/// Declares a token type for [`HasSealedToken`](crate::sealed::HasSealedToken),
+/// [`SealedToken`](crate::sealed::SealedToken), and
+/// [`Sealed`](crate::sealed::Sealed), which can only be named and created
+/// in the module where it is declared and its submodules.
+///
+/// The token is a unit struct, declared as `pub` in a private module and then
+/// imported, so that it can be used in public traits.
+///
+/// Only one token can be declared in each module.
+///
+/// See the [`sealed` module](crate::sealed) for more information.
+#[macro_export]
+macro_rules! sealed_token {
+    ($(#[$attr:meta])* $name:ident $(,)?) => {
+        #[doc(hidden)]
+        mod __unused_sealed_token {
+            $(#[$attr])*
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name;
+        }
+
+        use __unused_sealed_token::$name;
+    };
+}
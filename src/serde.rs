@@ -0,0 +1,56 @@
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// `Unused` is always serialized as a unit, which is `null` in JSON.
+impl<T: UnusedInner> Serialize for UnusedImpl<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+}
+
+/// Human-readable formats accept a unit, a `null`, an empty map, or a missing
+/// field. Other formats expect a unit, which takes up no space.
+impl<'de, T: UnusedInner> Deserialize<'de> for UnusedImpl<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_option(UnusedVisitor)?;
+        } else {
+            deserializer.deserialize_unit(UnusedVisitor)?;
+        }
+        Ok(Self::Unused)
+    }
+}
+
+struct UnusedVisitor;
+
+impl<'de> Visitor<'de> for UnusedVisitor {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a unit, null, or an empty map")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        match map.next_key::<de::IgnoredAny>()? {
+            None => Ok(()),
+            Some(_) => Err(de::Error::invalid_length(1, &self)),
+        }
+    }
+}
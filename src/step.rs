@@ -0,0 +1,31 @@
+use core::iter::Step;
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// There is only one `Unused`, so it can never be stepped forward or
+/// backward, and a range of `Unused`s contains at most one element.
+impl<T: UnusedInner> Step for UnusedImpl<T> {
+    #[inline]
+    fn steps_between(_start: &Self, _end: &Self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+
+    #[inline]
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        if count == 0 {
+            Some(start)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        if count == 0 {
+            Some(start)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use core::any::TypeId;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::Unused;
+
+/// A zero-sized key that identifies the type `T`, which is invariant over
+/// `T`.
+///
+/// `TypeTag` gives access to the [`TypeId`] and the name of `T` without a
+/// value of `T`:
+///
+/// ```
+/// use core::any::TypeId;
+///
+/// use unused::TypeTag;
+///
+/// assert_eq!(TypeTag::<u8>::type_id(), TypeId::of::<u8>());
+/// assert_eq!(TypeTag::<u8>::type_name(), "u8");
+/// ```
+///
+/// Tags of the same type are always equal. Tags of different types can be
+/// compared with [`same_type`](TypeTag::same_type):
+///
+/// ```
+/// use unused::TypeTag;
+///
+/// assert_eq!(TypeTag::<u8>::new(), TypeTag::<u8>::new());
+/// assert!(TypeTag::<u8>::new().same_type(&TypeTag::<u8>::new()));
+/// assert!(!TypeTag::<u8>::new().same_type(&TypeTag::<u16>::new()));
+/// ```
+///
+/// They cannot be compared with `==`, so that the type of a tag can always be
+/// inferred from the other side of a comparison:
+///
+/// ```compile_fail
+/// use unused::TypeTag;
+///
+/// assert!(TypeTag::<u8>::new() != TypeTag::<u16>::new());
+/// ```
+pub struct TypeTag<T: ?Sized> {
+    _unused: Unused!(T: invariant),
+}
+
+impl<T: ?Sized> TypeTag<T> {
+    /// Creates a new `TypeTag`.
+    pub const fn new() -> Self {
+        Self { _unused: Unused }
+    }
+
+    /// Returns the [`TypeId`] of `T`.
+    pub fn type_id() -> TypeId
+    where
+        T: 'static,
+    {
+        TypeId::of::<T>()
+    }
+
+    /// Returns the name of `T`, as given by [`core::any::type_name`].
+    pub fn type_name() -> &'static str {
+        core::any::type_name::<T>()
+    }
+
+    /// Returns whether `T` and `U` are the same type, by comparing their
+    /// [`TypeId`]s.
+    pub fn same_type<U: ?Sized + 'static>(&self, _other: &TypeTag<U>) -> bool
+    where
+        T: 'static,
+    {
+        Self::type_id() == TypeTag::<U>::type_id()
+    }
+}
+
+impl<T: ?Sized> Clone for TypeTag<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for TypeTag<T> {}
+
+impl<T: ?Sized> fmt::Debug for TypeTag<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypeTag").field(&Self::type_name()).finish()
+    }
+}
+
+impl<T: ?Sized> Default for TypeTag<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> PartialEq for TypeTag<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized> Eq for TypeTag<T> {}
+
+impl<T: ?Sized> Hash for TypeTag<T> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
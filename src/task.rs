@@ -0,0 +1,42 @@
+use core::ptr;
+use core::task::{RawWaker, RawWakerVTable, Waker};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(feature = "std")]
+use std::task::Wake;
+
+#[cfg(feature = "std")]
+use crate::inner::UnusedInner;
+#[cfg(feature = "std")]
+use crate::unused::UnusedImpl;
+
+const VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+fn clone(_data: *const ()) -> RawWaker {
+    raw_waker()
+}
+
+fn noop(_data: *const ()) {}
+
+fn raw_waker() -> RawWaker {
+    RawWaker::new(ptr::null(), &VTABLE)
+}
+
+/// Creates a [`Waker`] that does nothing when woken.
+pub(crate) fn noop_waker() -> Waker {
+    // SAFETY: every function in `VTABLE` ignores the data pointer and does
+    // nothing, which upholds the `RawWaker` contract.
+    unsafe { Waker::from_raw(raw_waker()) }
+}
+
+/// Waking an `Unused` does nothing.
+#[cfg(feature = "std")]
+// This is a no-op waker on purpose, so that an `Arc<Unused>` can be turned into
+// a `Waker`. `Waker::noop` would only replace `Unused::waker`, and requires
+// Rust 1.85.
+#[allow(unknown_lints, clippy::manual_noop_waker)]
+impl<T: UnusedInner> Wake for UnusedImpl<T> {
+    fn wake(self: Arc<Self>) {}
+
+    fn wake_by_ref(self: &Arc<Self>) {}
+}
@@ -0,0 +1,41 @@
+#[cfg(feature = "std")]
+use std::process::{self, ExitCode};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// A `no_std` equivalent of
+/// [`Termination`](https://doc.rust-lang.org/std/process/trait.Termination.html),
+/// for the return types of entry points.
+///
+/// ```
+/// use unused::{Termination, Unused};
+///
+/// fn entry() -> Unused!(()) {
+///     Unused
+/// }
+///
+/// assert_eq!(entry().report(), 0);
+/// ```
+pub trait Termination {
+    /// Reports the exit status, where `0` is success.
+    fn report(self) -> u8;
+}
+
+/// `Unused` always reports success.
+impl<T: UnusedInner> Termination for UnusedImpl<T> {
+    #[inline]
+    fn report(self) -> u8 {
+        0
+    }
+}
+
+/// `Unused` always reports success, which allows it to be returned from
+/// `main`.
+#[cfg(feature = "std")]
+impl<T: UnusedInner> process::Termination for UnusedImpl<T> {
+    #[inline]
+    fn report(self) -> ExitCode {
+        ExitCode::SUCCESS
+    }
+}
@@ -1,6 +1,8 @@
 extern crate std;
 
-use std::cell::UnsafeCell;
+use std::cell::{Cell, UnsafeCell};
+use std::fmt::Debug;
+use std::hash::Hash;
 use std::marker::PhantomPinned;
 use std::panic::{RefUnwindSafe, UnwindSafe};
 use std::prelude::v1::*;
@@ -8,6 +10,12 @@ use std::rc::Rc;
 
 use crate::Unused;
 
+fn auto_traits_are_implemented<T: ?Sized + Send + Sync + Unpin + UnwindSafe + RefUnwindSafe>() {}
+
+fn auto_traits_are_implemented_by<T: Send + Sync + Unpin + UnwindSafe + RefUnwindSafe>(_: T) {
+    auto_traits_are_implemented::<T>();
+}
+
 #[test]
 fn variance() {
     fn _simple_covariant<'a>(
@@ -27,15 +35,30 @@ fn variance() {
     ) -> Unused!(&'static str: contravariant, &'a str: covariant) {
         unused
     }
+
+    fn _lifetime_covariant<'a>(unused: Unused!('static: covariant)) -> Unused!('a: covariant) {
+        unused
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    fn _lifetime_contravariant<'a>(
+        unused: Unused!('a: contravariant),
+    ) -> Unused!('static: contravariant) {
+        unused
+    }
+
+    fn _lifetime_and_type<'a, T>(
+        unused: Unused!('static: covariant, T: invariant, 'a),
+    ) -> Unused!('a: covariant, T, 'a: invariant) {
+        unused
+    }
 }
 
 #[test]
 fn auto_traits() {
-    fn auto_traits_are_implemented<T: Send + Sync + Unpin + UnwindSafe + RefUnwindSafe>() {}
-
     auto_traits_are_implemented::<
         Unused!(Rc<str>: covariant, UnsafeCell<i32>, PhantomPinned: contravariant,),
-    >()
+    >();
 }
 
 #[test]
@@ -71,3 +94,2571 @@ pub fn _invariant_not_covariant() {}
 /// }
 /// ```
 pub fn _invariant_not_contravariant() {}
+
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// fn invariant<'a>(unused: Unused!('static)) -> Unused!('a) {
+///     unused
+/// }
+/// ```
+pub fn _invariant_lifetime_not_covariant() {}
+
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// fn contravariant<'a>(unused: Unused!('static: contravariant)) -> Unused!('a: contravariant) {
+///     unused
+/// }
+/// ```
+pub fn _contravariant_lifetime_not_covariant() {}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Foo {
+        value: u8,
+        unused: Unused!(Rc<str>),
+    }
+
+    let foo: Foo = Foo {
+        value: 1,
+        unused: Unused,
+    };
+    let json = serde_json::to_string(&foo).unwrap();
+    assert_eq!(json, r#"{"value":1,"unused":null}"#);
+    assert_eq!(serde_json::from_str::<Foo>(&json).unwrap(), foo);
+
+    assert_eq!(
+        serde_json::from_str::<Foo>(r#"{"value":1,"unused":{}}"#).unwrap(),
+        foo,
+    );
+    assert_eq!(serde_json::from_str::<Foo>(r#"{"value":1}"#).unwrap(), foo,);
+    assert!(serde_json::from_str::<Foo>(r#"{"value":1,"unused":{"a":1}}"#).is_err());
+    assert!(serde_json::from_str::<Foo>(r#"{"value":1,"unused":0}"#).is_err());
+}
+
+#[test]
+fn phantom_data() {
+    use core::marker::PhantomData;
+
+    fn _variance_is_preserved<'a>(
+        unused: Unused!(&'static str: covariant, &'a str: contravariant),
+    ) -> Unused!(&'a str: covariant, &'static str: contravariant) {
+        let phantom = unused.phantom();
+        phantom.into()
+    }
+
+    let unused: Unused!(Rc<str>, PhantomPinned: covariant) = Unused;
+    let phantom: PhantomData<_> = unused.into();
+    auto_traits_are_implemented_by(phantom);
+    let round_trip: Unused!(Rc<str>, PhantomPinned: covariant) = phantom.into();
+    assert_eq!(round_trip, unused);
+}
+
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// let unused: Unused!(u8) = Unused;
+/// match unused {
+///     Unused => {}
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::{Unused, __Inconstruable};
+///
+/// let unused: Unused!(u8) = Unused;
+/// match unused {
+///     Unused => {}
+///     __Inconstruable(_) => {}
+/// }
+/// ```
+pub fn _matches_require_a_wildcard() {}
+
+#[cfg(feature = "unit_cmp")]
+#[test]
+fn phantom_data_eq() {
+    use core::marker::PhantomData;
+
+    assert!(<Unused!(u8)>::new() == PhantomData::<fn(u8) -> u8>);
+    assert_eq!(
+        <Unused!(Rc<u8>)>::new(),
+        PhantomData::<fn(Rc<u8>) -> Rc<u8>>
+    );
+    assert_eq!(<Unused!(str: covariant)>::new(), PhantomData::<str>);
+    assert_eq!(
+        <Unused!(Rc<u8>: contravariant)>::new(),
+        PhantomData::<fn(Rc<u8>)>
+    );
+}
+
+/// Without the `unit_cmp` feature, `Unused` can only be compared with
+/// itself, so the other side of a comparison can be inferred.
+#[cfg(not(feature = "unit_cmp"))]
+#[test]
+fn comparison_is_inferred() {
+    let a: Unused!(u8) = Unused;
+    assert!(a == Default::default());
+    assert!(a <= Default::default());
+    assert_eq!(a, Default::default());
+
+    let b: Unused!(str: covariant) = Unused;
+    assert!(b == Unused);
+    assert!(b == Default::default());
+}
+
+#[test]
+fn phantom_data_forms() {
+    use core::marker::PhantomData;
+
+    use crate::{from_phantom_variance, into_phantom_variance};
+
+    let invariant: Unused!(Rc<u8>: invariant) =
+        from_phantom_variance(PhantomData::<fn(Rc<u8>) -> Rc<u8>>);
+    let phantom: PhantomData<fn(Rc<u8>) -> Rc<u8>> = into_phantom_variance(invariant);
+    let round_trip: Unused!(Rc<u8>: invariant) = from_phantom_variance(phantom);
+    assert_eq!(round_trip, invariant);
+
+    let covariant: Unused!(str: covariant) = from_phantom_variance(PhantomData::<str>);
+    let phantom: PhantomData<str> = into_phantom_variance(covariant);
+    let round_trip: Unused!(str: covariant) = from_phantom_variance(phantom);
+    assert_eq!(round_trip, covariant);
+
+    let contravariant: Unused!(Rc<u8>: contravariant) =
+        from_phantom_variance(PhantomData::<fn(Rc<u8>)>);
+    let phantom: PhantomData<fn(Rc<u8>)> = into_phantom_variance(contravariant);
+    let round_trip: Unused!(Rc<u8>: contravariant) = from_phantom_variance(phantom);
+    assert_eq!(round_trip, contravariant);
+
+    fn _covariant<'a>(phantom: PhantomData<&'static str>) -> Unused!(&'a str: covariant) {
+        from_phantom_variance(phantom)
+    }
+
+    fn _contravariant<'a>(
+        phantom: PhantomData<fn(&'a str)>,
+    ) -> Unused!(&'static str: contravariant) {
+        from_phantom_variance(phantom)
+    }
+
+    const INVARIANT: PhantomData<fn(u8) -> u8> = into_phantom_variance(<Unused!(u8)>::new());
+    const _: Unused!(u8) = from_phantom_variance(INVARIANT);
+
+    // Only the conversion to and from the types of the `Unused` is a `From`
+    // implementation, so `.into()` can infer the `PhantomData`.
+    let single: Unused!(u8) = Unused;
+    let phantom: PhantomData<_> = single.into();
+    let single: Unused!(u8) = phantom.into();
+    assert_eq!(single, Unused);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn error() {
+    use std::error::Error;
+
+    let error: Box<dyn Error> = Box::new(<Unused!(u8)>::default());
+    assert_eq!(error.to_string(), "Unused");
+    assert!(error.source().is_none());
+
+    fn fails() -> Result<(), Unused!(Rc<u8>)> {
+        Err(Unused)
+    }
+
+    fn propagates() -> Result<(), Box<dyn Error + Send + Sync>> {
+        fails()?;
+        Ok(())
+    }
+    assert_eq!(propagates().unwrap_err().to_string(), "Unused");
+}
+
+#[test]
+fn control_flow() {
+    use core::convert::Infallible;
+    use core::ops::ControlFlow;
+
+    use crate::{End, PhantomBottom};
+
+    trait Visitor {
+        fn visit(&self, item: u8) -> ControlFlow<(), ()>;
+    }
+
+    struct StopAt(u8, Cell<usize>);
+
+    impl Visitor for StopAt {
+        fn visit(&self, item: u8) -> ControlFlow<(), ()> {
+            self.1.set(self.1.get() + 1);
+            if item == self.0 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    fn visit_items<T: Visitor>(v: &T) -> crate::unused::UnusedImpl<End> {
+        (0..10).try_for_each(|item| v.visit(item)).into()
+    }
+
+    let visitor = StopAt(3, Cell::new(0));
+    let visited: PhantomBottom = visit_items(&visitor);
+    assert_eq!(visited, Unused);
+    assert_eq!(visitor.1.get(), 4);
+
+    let flow: ControlFlow<(), ()> = <Unused!(u8)>::new().into();
+    assert_eq!(flow, ControlFlow::Continue(()));
+    assert_eq!(<Unused!(u8)>::from(ControlFlow::Break(())), Unused);
+
+    fn never_breaks(unused: Unused!(u8)) -> ControlFlow<Infallible, usize> {
+        unused.to_continue()?;
+        ControlFlow::Continue(1)
+    }
+    assert_eq!(never_breaks(Unused), ControlFlow::Continue(1));
+}
+
+#[test]
+fn variance_info() {
+    use crate::unused::UnusedImpl;
+    use crate::{Covariant, End, Invariant, Variance};
+
+    let unused: Unused!(u8, u16: covariant, u32: contravariant) = Unused;
+    let info = unused.variance_info();
+    assert_eq!(
+        info,
+        [
+            Variance::Invariant,
+            Variance::Covariant,
+            Variance::Contravariant
+        ]
+    );
+    assert_eq!(info.len(), 3);
+    assert!(!info.is_empty());
+    assert_eq!(info.get(1), Some(Variance::Covariant));
+    assert_eq!(info.get(3), None);
+    assert_eq!(
+        info.iter().rev().collect::<Vec<_>>(),
+        [
+            Variance::Contravariant,
+            Variance::Covariant,
+            Variance::Invariant
+        ]
+    );
+    assert_eq!(info.iter().len(), 3);
+    assert_eq!(
+        format!("{:?}", info),
+        "[Invariant, Covariant, Contravariant]"
+    );
+    assert_eq!(Variance::Contravariant.to_string(), "contravariant");
+
+    let empty = <UnusedImpl<End>>::new().variance_info();
+    assert!(empty.is_empty());
+    assert_eq!(empty, &[][..]);
+
+    type Merged = UnusedImpl<(Covariant<u8, End>, End, Invariant<u16, Covariant<u32, End>>)>;
+    assert_eq!(
+        Merged::new().variance_info(),
+        [
+            Variance::Covariant,
+            Variance::Invariant,
+            Variance::Covariant
+        ]
+    );
+    assert_eq!(
+        format!("{:#?}", Merged::new()),
+        "Unused(covariant, invariant, covariant)"
+    );
+}
+
+#[test]
+fn compose_variance() {
+    use crate::unused::UnusedImpl;
+    use crate::{ComposeVariance, Contravariant, Covariant, End, Invariant};
+
+    type Inv = Invariant<(), End>;
+    type Co = Covariant<(), End>;
+    type Contra = Contravariant<(), End>;
+
+    fn same<T>(_: T, _: T) {}
+
+    macro_rules! check {
+        ($($outer:ident, $inner:ident => $output:ident;)*) => {
+            $(
+                same(
+                    UnusedImpl::<ComposeVariance<$outer, $inner, Rc<u8>>>::new(),
+                    UnusedImpl::<$output<Rc<u8>, End>>::new(),
+                );
+            )*
+        };
+    }
+
+    check! {
+        Inv, Inv => Invariant;
+        Inv, Co => Invariant;
+        Inv, Contra => Invariant;
+        Co, Inv => Invariant;
+        Co, Co => Covariant;
+        Co, Contra => Contravariant;
+        Contra, Inv => Invariant;
+        Contra, Co => Contravariant;
+        Contra, Contra => Covariant;
+    }
+
+    type Composed<V1, V2, T> = UnusedImpl<ComposeVariance<V1, V2, T>>;
+
+    fn co_co<'a>(unused: Composed<Co, Co, &'static str>) -> Composed<Co, Co, &'a str> {
+        unused
+    }
+
+    fn contra_contra<'a>(
+        unused: Composed<Contra, Contra, &'static str>,
+    ) -> Composed<Contra, Contra, &'a str> {
+        unused
+    }
+
+    fn co_contra(unused: Composed<Co, Contra, &str>) -> Composed<Co, Contra, &'static str> {
+        unused
+    }
+
+    fn contra_co(unused: Composed<Contra, Co, &str>) -> Composed<Contra, Co, &'static str> {
+        unused
+    }
+
+    let _co_co = co_co(UnusedImpl::new());
+    let _contra_contra = contra_contra(UnusedImpl::new());
+    let _co_contra = co_contra(UnusedImpl::<Contravariant<&str, End>>::new());
+    let _contra_co = contra_co(UnusedImpl::<Contravariant<&str, End>>::new());
+}
+
+/// ```compile_fail
+/// use unused::{ComposeVariance, Covariant, End, Invariant, Unused};
+///
+/// type Composed<T> = Unused<ComposeVariance<Covariant<(), End>, Invariant<(), End>, T>>;
+///
+/// fn shorten<'a>(unused: Composed<&'static str>) -> Composed<&'a str> {
+///     unused
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::{ComposeVariance, Contravariant, End, Unused};
+///
+/// type Composed<T> = Unused<ComposeVariance<Contravariant<(), End>, Contravariant<(), End>, T>>;
+///
+/// fn lengthen<'a>(unused: Composed<&'a str>) -> Composed<&'static str> {
+///     unused
+/// }
+/// ```
+pub fn _compose_variance_is_checked() {}
+
+#[test]
+fn tuples() {
+    use crate::unused::UnusedImpl;
+    use crate::{Contravariant, Covariant, End, Invariant};
+
+    fn copy_is_implemented<T: Copy>() {}
+
+    type Pair = UnusedImpl<(Invariant<Rc<u8>, End>, Covariant<*const u16, End>)>;
+    auto_traits_are_implemented::<Pair>();
+    copy_is_implemented::<Pair>();
+    assert_eq!(core::mem::size_of::<Pair>(), 0);
+    assert_eq!(Pair::ARITY, 2);
+
+    type Single = UnusedImpl<(Contravariant<Cell<u8>, End>,)>;
+    auto_traits_are_implemented::<Single>();
+    copy_is_implemented::<Single>();
+    assert_eq!(format!("{:#?}", Single::new()), "Unused(contravariant)");
+
+    type C<T> = Covariant<T, End>;
+    type Eight = UnusedImpl<(
+        C<u8>,
+        C<u16>,
+        C<u32>,
+        C<u64>,
+        C<i8>,
+        C<i16>,
+        Invariant<i32, C<i64>>,
+        End,
+    )>;
+    auto_traits_are_implemented::<Eight>();
+    copy_is_implemented::<Eight>();
+    assert_eq!(core::mem::size_of::<Eight>(), 0);
+    assert_eq!(Eight::ARITY, 8);
+    assert_eq!(
+        format!("{:#?}", Eight::new()),
+        "Unused(covariant, covariant, covariant, covariant, covariant, covariant, invariant, \
+         covariant)"
+    );
+}
+
+#[test]
+fn merge_and_split() {
+    use crate::unused::UnusedImpl;
+    use crate::{merge, merge3, merge4, split, split3, split4, Contravariant, Covariant, End};
+
+    type Merged<A, B> = UnusedImpl<(Covariant<A, End>, Contravariant<B, End>)>;
+
+    fn merge_variance<'a>(ab: Merged<&'static str, &'a str>) -> Merged<&'a str, &'static str> {
+        ab
+    }
+
+    let a: Unused!(Rc<str>) = Unused;
+    let b: Unused!(UnsafeCell<u8>: covariant) = Unused;
+    let c: Unused!(PhantomPinned: contravariant) = Unused;
+    let d: Unused!(*const u8) = Unused;
+
+    merge_variance(Unused);
+
+    let ab = merge(a, b);
+    auto_traits_are_implemented_by(ab);
+    assert_eq!(split(ab), (a, b));
+
+    let abc = merge3(a, b, c);
+    auto_traits_are_implemented_by(abc);
+    assert_eq!(split3(abc), (a, b, c));
+
+    let abcd = merge4(a, b, c, d);
+    auto_traits_are_implemented_by(abcd);
+    assert_eq!(split4(abcd), (a, b, c, d));
+}
+
+#[test]
+fn iterator() {
+    let unused = <Unused!(u8)>::default();
+    let mut iter = unused.into_iter();
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(
+        unused.into_iter().rev().fuse().collect::<Vec<_>>(),
+        Vec::new()
+    );
+    assert_eq!(unused.into_iter().chain(unused).count(), 0);
+    let collected: Vec<u8> = unused.into_iter().map(|never| match never {}).collect();
+    assert!(collected.is_empty());
+}
+
+/// `Unused` is not an `Iterator`, so the methods of the comparison traits are
+/// not shadowed by those of `Iterator`:
+///
+/// ```
+/// use core::cmp::Ordering;
+///
+/// use unused::Unused;
+///
+/// let a: Unused!(u8) = Unused;
+/// let b: Unused!(u8) = Unused;
+/// assert!(a.eq(&b));
+/// assert!(!a.ne(&b));
+/// assert_eq!(a.cmp(&b), Ordering::Equal);
+/// assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+/// assert_eq!(a.max(b), Unused);
+/// assert_eq!(a.min(b), Unused);
+/// ```
+pub fn _comparison_methods_are_not_shadowed() {}
+
+#[test]
+fn fmt_write() {
+    use core::fmt::Write;
+
+    let mut unused = <Unused!(u8)>::default();
+    assert_eq!(write!(unused, "{} {}", 1, "a"), Ok(()));
+    assert_eq!(unused.write_char('a'), Ok(()));
+}
+
+#[test]
+fn write_sink() {
+    use core::fmt::Write;
+
+    use crate::WriteSink;
+
+    fn send_sync<T: Send + Sync>(_: &T) {}
+
+    let mut sink = WriteSink::<Rc<Cell<u8>>>::new();
+    send_sync(&sink);
+    assert_eq!((sink.chars(), sink.bytes()), (0, 0));
+    sink.write_str("abc").unwrap();
+    sink.write_char('é').unwrap();
+    let quarter = '¼';
+    write!(sink, "{}{}", 12, quarter).unwrap();
+    assert_eq!(sink.chars(), 3 + 1 + 2 + 1);
+    assert_eq!(sink.bytes(), 3 + 2 + 2 + 2);
+
+    let clone = sink.clone();
+    sink.write_str("d").unwrap();
+    assert_eq!((clone.chars(), sink.chars()), (7, 8));
+    assert_eq!((clone.bytes(), sink.bytes()), (9, 10));
+    assert_eq!(format!("{:?}", sink), "WriteSink { chars: 8, bytes: 10 }");
+    assert_eq!(WriteSink::<str>::default().bytes(), 0);
+    assert_eq!(
+        core::mem::size_of::<WriteSink<String>>(),
+        2 * core::mem::size_of::<usize>()
+    );
+
+    // A char may take up more than one byte.
+    let mut sink = WriteSink::<u8>::new();
+    sink.write_str("é").unwrap();
+    assert_eq!((sink.chars(), sink.bytes()), (1, 2));
+    let mut sink = WriteSink::<u8>::new();
+    sink.write_char('é').unwrap();
+    assert_eq!((sink.chars(), sink.bytes()), (1, 2));
+
+    // The counts saturate instead of overflowing.
+    let mut sink = WriteSink::<u8>::new();
+    sink.chars = usize::MAX;
+    sink.bytes = usize::MAX - 1;
+    sink.write_str("é").unwrap();
+    assert_eq!((sink.chars(), sink.bytes()), (usize::MAX, usize::MAX));
+}
+
+/// ```compile_fail
+/// use core::fmt::Write;
+///
+/// use unused::WriteSink;
+///
+/// let mut sink = WriteSink::<u8>::new();
+/// let copy = sink;
+/// sink.write_str("lost").unwrap();
+/// ```
+pub fn _write_sink_is_not_copy() {}
+
+#[test]
+fn unused_with() {
+    use core::mem::{align_of, size_of};
+
+    use crate::{Covariant, End, Invariant, UnusedWith};
+
+    fn send_sync<T: Send + Sync>() {}
+
+    type Unit = UnusedWith<Invariant<String, End>, ()>;
+    send_sync::<Unit>();
+    send_sync::<UnusedWith<Invariant<Rc<Cell<u8>>, End>, ()>>();
+    assert_eq!(size_of::<Unit>(), 0);
+    assert_eq!(align_of::<Unit>(), 1);
+    assert_eq!(size_of::<UnusedWith<Invariant<String, End>, u32>>(), 4);
+    assert_eq!(align_of::<UnusedWith<Invariant<String, End>, u32>>(), 4);
+
+    let unit = Unit::new(());
+    assert_eq!(unit, Unit::default());
+    assert_eq!(unit.unused(), Unused);
+    assert_eq!(
+        format!("{:?}", unit),
+        "UnusedWith { unused: Unused, value: () }"
+    );
+
+    let mut with = UnusedWith::<Covariant<&str, End>, u32>::new(1);
+    *with.value_mut() += 1;
+    let copy = with;
+    assert_eq!(copy.into_value(), 2);
+    assert!(UnusedWith::<Covariant<&str, End>, u32>::new(1) < with);
+    let (unused, value) = with.into_parts();
+    assert_eq!(UnusedWith::from((unused, value)), with);
+}
+
+/// ```compile_fail
+/// use std::rc::Rc;
+///
+/// use unused::{Unused, UnusedWith};
+///
+/// fn send<T: Send>() {}
+///
+/// send::<UnusedWith<Unused!(u8), Rc<u8>>>();
+/// ```
+pub fn _unused_with_inherits_auto_traits_of_its_value() {}
+
+#[cfg(feature = "std")]
+#[test]
+fn io() {
+    use std::io::{self, Read, Seek, SeekFrom, Write};
+
+    let mut unused = <Unused!(u8)>::default();
+    assert_eq!(unused.write(b"abc").unwrap(), 3);
+    unused.write_all(b"abc").unwrap();
+    unused.flush().unwrap();
+    assert_eq!(io::copy(&mut &b"abc"[..], &mut unused).unwrap(), 3);
+
+    let mut buf = [1; 3];
+    assert_eq!(unused.read(&mut buf).unwrap(), 0);
+    assert_eq!(buf, [1; 3]);
+    let mut string = String::new();
+    assert_eq!(unused.read_to_string(&mut string).unwrap(), 0);
+    assert!(string.is_empty());
+
+    assert_eq!(unused.seek(SeekFrom::Start(5)).unwrap(), 0);
+    assert_eq!(unused.seek(SeekFrom::End(-5)).unwrap(), 0);
+    assert_eq!(unused.seek(SeekFrom::Current(5)).unwrap(), 0);
+    assert_eq!(unused.stream_position().unwrap(), 0);
+    unused.rewind().unwrap();
+
+    let mut sink = crate::WriteSink::<Rc<u8>>::new();
+    sink.write_all(b"abc").unwrap();
+    assert_eq!(io::copy(&mut &b"\xff\xfe"[..], &mut sink).unwrap(), 2);
+    assert_eq!((sink.chars(), sink.bytes()), (0, 5));
+}
+
+#[test]
+fn zero_sized() {
+    use crate::{assert_align_one, assert_zero_sized};
+
+    struct Large {
+        _bytes: [u8; 1024],
+        _string: String,
+    }
+
+    assert_zero_sized!(Unused!(u8));
+    assert_zero_sized!(Unused!(String: covariant));
+    assert_zero_sized!(Unused!(Large: contravariant, Large));
+    assert_align_one!(Unused!(u8));
+    assert_align_one!(Unused!(String: covariant));
+    assert_align_one!(Unused!(Large: contravariant, Large));
+}
+
+#[test]
+fn const_contexts() {
+    use crate::unused::UnusedImpl;
+    use crate::End;
+
+    struct Foo<T> {
+        value: u64,
+        unused: Unused!(T),
+    }
+
+    const fn foo<T>(value: u64) -> Foo<T> {
+        Foo {
+            value,
+            unused: <Unused!(T)>::new(),
+        }
+    }
+
+    const INSTANCE: UnusedImpl<End> = UnusedImpl::Unused;
+    const FOO: Foo<Rc<str>> = foo(1);
+    const PHANTOM: core::marker::PhantomData<crate::Invariant<u64, End>> =
+        <Unused!(u64)>::new().phantom();
+
+    assert_eq!(INSTANCE, UnusedImpl::new());
+    assert_eq!(FOO.value, 1);
+    assert_eq!(FOO.unused, Unused);
+    assert_eq!(PHANTOM, <Unused!(u64)>::new().phantom());
+}
+
+#[test]
+fn negative() {
+    use crate::{assert_align_one, assert_zero_sized, NeitherSendNorSync, NonSend, NonSync};
+
+    fn is_send<T: Send>() {}
+    fn is_sync<T: Sync>() {}
+    fn other_traits<T: Unpin + UnwindSafe + RefUnwindSafe + Default + Copy + Ord + Hash + Debug>() {
+    }
+
+    is_sync::<NonSend<u8>>();
+    is_sync::<NonSend<Rc<str>>>();
+    is_send::<NonSync<u8>>();
+    is_send::<NonSync<Rc<str>>>();
+
+    other_traits::<NonSend<Rc<str>>>();
+    other_traits::<NonSync<PhantomPinned>>();
+    other_traits::<NeitherSendNorSync<UnsafeCell<u8>>>();
+
+    assert_zero_sized!(NonSend<String>);
+    assert_zero_sized!(NonSync<String>);
+    assert_zero_sized!(NeitherSendNorSync<String>);
+    assert_align_one!(NeitherSendNorSync<u64>);
+
+    assert_eq!(NonSend::<str>::new(), NonSend::default());
+    assert_eq!(format!("{:?}", NonSync::<str>::new()), "NonSync");
+}
+
+#[test]
+fn non_copy_and_non_clone() {
+    use crate::{assert_zero_sized, NonClone, NonCopy};
+
+    fn auto_traits<T: Send + Sync + Unpin + UnwindSafe + RefUnwindSafe>() {}
+    fn other_traits<T: Default + Eq + Ord + Hash + Debug>() {}
+    fn is_clone<T: Clone>() {}
+
+    auto_traits::<NonCopy<Rc<String>>>();
+    auto_traits::<NonClone<Rc<String>>>();
+    other_traits::<NonCopy<Rc<String>>>();
+    other_traits::<NonClone<Rc<String>>>();
+    is_clone::<NonCopy<Rc<String>>>();
+
+    assert!(!core::mem::needs_drop::<NonCopy<String>>());
+    assert_zero_sized!(NonCopy<String>);
+    assert_zero_sized!(NonClone<String>);
+
+    let non_copy = NonCopy::<str>::new();
+    assert_eq!(non_copy.clone(), non_copy);
+    assert_eq!(format!("{:?}", NonClone::<str>::default()), "NonClone");
+}
+
+#[test]
+fn pin() {
+    use crate::{assert_zero_sized, ForceUnpin, NotUnpin, PinnedUnused};
+
+    fn auto_traits<T: Send + Sync + UnwindSafe + RefUnwindSafe>() {}
+    fn is_unpin<T: Unpin>() {}
+    fn other_traits<T: Default + Copy + Ord + Hash + Debug>() {}
+
+    auto_traits::<NotUnpin<Rc<str>>>();
+    auto_traits::<ForceUnpin<Rc<str>>>();
+    is_unpin::<ForceUnpin<PhantomPinned>>();
+    other_traits::<NotUnpin<Rc<str>>>();
+    other_traits::<ForceUnpin<PhantomPinned>>();
+    auto_traits::<PinnedUnused<Rc<str>>>();
+    other_traits::<PinnedUnused<Rc<str>>>();
+
+    assert_zero_sized!(NotUnpin<String>);
+    assert_zero_sized!(PinnedUnused<String>);
+    assert_zero_sized!(ForceUnpin<String>);
+
+    assert_eq!(NotUnpin::<str>::new(), NotUnpin::default());
+    assert_eq!(format!("{:?}", ForceUnpin::<str>::new()), "ForceUnpin");
+}
+
+#[test]
+fn arithmetic() {
+    let x: Unused!(u8) = Unused;
+    assert_eq!(x + x, Unused);
+    assert_eq!(x - x, Unused);
+    assert_eq!(x * x, Unused);
+    assert_eq!(x / x, Unused);
+    assert_eq!(x % x, Unused);
+    assert_eq!(x & x, Unused);
+    assert_eq!(x | x, Unused);
+    assert_eq!(x ^ x, Unused);
+    assert_eq!(x << x, Unused);
+    assert_eq!(x >> x, Unused);
+    assert_eq!(-x, Unused);
+    assert_eq!(!x, Unused);
+    assert_eq!(core::mem::size_of_val(&(!-((x + x) << x))), 0);
+}
+
+#[test]
+fn range_and_index() {
+    use core::ops::{Bound, RangeBounds};
+
+    let r: Unused!(u8) = Unused;
+    assert!(r.contains(&()));
+    assert_eq!(r.start_bound(), Bound::Unbounded);
+    assert_eq!(r.end_bound(), Bound::Unbounded);
+    assert!(r[..].is_empty());
+    assert!(r[0..0].is_empty());
+    assert_eq!(<Unused!(u8)>::default()[..], []);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn index_panics() {
+    let unused: Unused!(u8) = Unused;
+    assert_eq!(unused[0], ());
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn num_traits() {
+    use num_traits::{Num, One, Zero};
+
+    fn num<T: Num>() -> T {
+        T::one() + T::zero()
+    }
+
+    assert_eq!(<Unused!(u8)>::zero(), Unused);
+    assert!(<Unused!(u8)>::zero().is_zero());
+    assert_eq!(<Unused!(u8)>::one(), Unused);
+    assert!(<Unused!(u8)>::one().is_one());
+    assert_eq!(<Unused!(u8)>::from_str_radix("ff", 16), Ok(Unused));
+    assert_eq!(num::<Unused!(u8)>(), Unused);
+}
+
+#[test]
+fn unit_references() {
+    use core::borrow::{Borrow, BorrowMut};
+
+    fn borrows_unit<B: Borrow<()>>(b: B) -> bool {
+        b.borrow() == &()
+    }
+
+    let mut unused: Unused!(u8) = Unused;
+    let unit: &() = &unused;
+    assert_eq!(unit, &());
+    let unit: &mut () = &mut unused;
+    *unit = ();
+    assert_eq!(AsRef::<()>::as_ref(&unused), &());
+    assert_eq!(AsMut::<()>::as_mut(&mut unused), &mut ());
+    assert_eq!(BorrowMut::<()>::borrow_mut(&mut unused), &mut ());
+    assert!(borrows_unit(unused));
+}
+
+#[test]
+fn as_empty_slice() {
+    use core::convert::Infallible;
+
+    fn len<S: AsRef<[Infallible]>>(s: S) -> usize {
+        s.as_ref().len()
+    }
+
+    let unused = <Unused!(u8)>::default();
+    let slice = AsRef::<[Infallible]>::as_ref(&unused);
+    assert!(slice.is_empty());
+    assert!(slice.as_ptr().is_aligned());
+    assert_eq!(len(unused), 0);
+
+    let array: &[(); 0] = unused.as_ref();
+    assert_eq!(array, &[]);
+    assert_eq!(array.len(), 0);
+}
+
+#[test]
+fn unit_conversions() {
+    use core::convert::TryFrom;
+
+    let unused: Unused!(i32) = ().into();
+    let _: () = unused.into();
+    let _: () = <Unused!(i32)>::default().into();
+
+    assert_eq!(<Unused!(i32)>::from(true), Unused);
+    assert_eq!(<Unused!(i32)>::from(false), Unused);
+    assert_eq!(<Unused!(i32)>::try_from(0u8), Ok(Unused));
+    assert_eq!(<Unused!(i32)>::try_from(1u8), Err(()));
+    assert!(<Unused!(u8)>::try_from(0u8).is_ok());
+    assert!(<Unused!(u8)>::try_from(1u8).is_err());
+    assert_eq!(<Unused!(i32)>::try_from(0u128), Ok(Unused));
+    assert_eq!(<Unused!(i32)>::try_from(u128::MAX), Err(()));
+    assert!(<Unused!(i32)>::try_from("Unused").is_ok());
+    assert_eq!(<Unused!(i32)>::try_from(""), Ok(Unused));
+    assert_eq!(<Unused!(i32)>::try_from("unused"), Err(()));
+    assert_eq!(<Unused!(i32)>::try_from("anything"), Err(()));
+
+    let unused: Unused!(u8) = Unused;
+    assert_eq!(<Unused!(u8)>::try_from(&*unused.to_string()), Ok(unused));
+}
+
+#[cfg(feature = "unit_cmp")]
+#[test]
+fn unit_cmp() {
+    use core::cmp::Ordering;
+
+    assert!(<Unused!(u8)>::default() == ());
+    assert!(<Unused!(u8)>::default() <= ());
+    assert!(<Unused!(u8)>::default() >= ());
+    assert_eq!(
+        <Unused!(u8)>::default().partial_cmp(&()),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+fn display_and_from_str() {
+    let unused: Unused!(u8) = Unused;
+    assert_eq!(unused.to_string(), "Unused");
+    assert_eq!(format!("{:>8}", unused), "  Unused");
+    assert_eq!("anything".parse::<Unused!(u8)>(), Ok(Unused));
+    assert_eq!(unused.to_string().parse::<Unused!(u8)>(), Ok(unused));
+}
+
+#[test]
+fn debug() {
+    let unused: Unused!(u8: covariant, u16, u32: contravariant) = Unused;
+    assert_eq!(format!("{:?}", unused), "Unused");
+    assert_eq!(
+        format!("{:#?}", unused),
+        "Unused(covariant, invariant, contravariant)",
+    );
+    assert_eq!(
+        format!("{:#?}", crate::merge(unused, <Unused!('static)>::new())),
+        "Unused(covariant, invariant, contravariant, invariant)",
+    );
+    assert_eq!(format!("{:#?}", crate::PhantomBottom::new()), "Unused");
+    assert_eq!(
+        format!("{:#?}", Some(<Unused!(u8)>::new())),
+        "Some(\n    Unused(invariant),\n)"
+    );
+}
+
+#[test]
+fn numeric_fmt() {
+    let unused: Unused!(u8) = Unused;
+    assert_eq!(format!("{:b}", unused), "0");
+    assert_eq!(format!("{:#b}", unused), "0b0");
+    assert_eq!(format!("{:o}", unused), "0");
+    assert_eq!(format!("{:#o}", unused), "0o0");
+    assert_eq!(format!("{:x}", unused), "0");
+    assert_eq!(format!("{:#x}", unused), "0x0");
+    assert_eq!(format!("{:X}", unused), "0");
+    assert_eq!(format!("{:#04X}", unused), "0x00");
+    assert_eq!(format!("{:e}", unused), "0e0");
+    assert_eq!(format!("{:E}", unused), "0E0");
+    assert_eq!(format!("{:p}", unused), "0x0");
+    let _ = format!("{:p}", &unused as *const _);
+}
+
+#[test]
+fn pointer_fmt() {
+    fn address<P: core::fmt::Pointer>(pointer: P) -> String {
+        format!("{:p}", pointer)
+    }
+
+    assert_eq!(address(<Unused!(u8)>::default()), "0x0");
+    assert_eq!(address(<Unused!(str: covariant)>::default()), "0x0");
+    assert_eq!(address(core::ptr::null::<u8>()), "0x0");
+    assert_eq!(format!("{:>5p}", <Unused!(u8)>::default()), "  0x0");
+    assert_eq!(format!("{:<5p}|", <Unused!(u8)>::default()), "0x0  |");
+}
+
+#[test]
+fn values() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    use crate::unused::UnusedImpl;
+
+    fn exercise<T: crate::inner::UnusedInner>() {
+        let unused = UnusedImpl::<T>::new();
+        let mut cloned = unused;
+        cloned.clone_from(&unused);
+        assert_eq!(unused, cloned);
+        assert_eq!(Ord::cmp(&unused, &cloned), core::cmp::Ordering::Equal);
+
+        let mut hasher = DefaultHasher::new();
+        unused.hash(&mut hasher);
+        assert_eq!(hasher.finish(), DefaultHasher::new().finish());
+
+        match unused {
+            Unused => {}
+            crate::__Inconstruable(_) => unreachable!(),
+        }
+
+        let boxed = Box::new(unused);
+        assert_eq!(*boxed, unused);
+        let mut values = vec![unused; 16];
+        values.push(cloned);
+        assert_eq!(values.len(), 17);
+        assert_eq!(core::mem::size_of_val(&values[..]), 0);
+        assert_eq!(core::mem::size_of::<UnusedImpl<T>>(), 0);
+    }
+
+    exercise::<crate::End>();
+    exercise::<crate::Invariant<String, crate::End>>();
+    exercise::<crate::Covariant<Vec<u8>, crate::End>>();
+    exercise::<crate::Contravariant<Box<u32>, crate::End>>();
+    exercise::<
+        crate::Invariant<
+            String,
+            crate::Covariant<Vec<u8>, crate::Contravariant<Box<u32>, crate::End>>,
+        >,
+    >();
+
+    assert_eq!(
+        core::mem::size_of::<Unused!(String, Vec<u8>, Box<u32>: covariant)>(),
+        0
+    );
+}
+
+#[test]
+fn arity() {
+    use crate::inner::UnusedInner;
+
+    const THREE: usize = <Unused!(u8, u16, u32)>::ARITY;
+    assert_eq!(THREE, 3);
+
+    assert_eq!(crate::UnusedImpl::<crate::End>::ARITY, 0);
+    assert_eq!(<Unused!(u8)>::ARITY, 1);
+    assert_eq!(<Unused!(u8, u16: covariant)>::ARITY, 2);
+    assert_eq!(<Unused!(u8, 'static: contravariant, u32)>::ARITY, 3);
+    assert_eq!(<Unused!(u8, u16, u32, u64)>::ARITY, 4);
+    assert_eq!(<Unused!(u8, u16, u32, u64, u128)>::ARITY, 5);
+
+    const _: () = assert!(crate::End::ZERO_SIZED);
+    const _: () =
+        assert!(<crate::Invariant<String, crate::Covariant<Vec<u8>, crate::End>>>::ZERO_SIZED);
+    assert_eq!(<(crate::End, crate::Invariant<u8, crate::End>)>::ARITY, 1);
+}
+
+#[test]
+fn marker_trait_objects() {
+    use crate::{Contravariant, Covariant, End, Invariant, UnusedImpl, UnusedMarker};
+
+    let markers: Vec<Box<dyn UnusedMarker>> = vec![
+        Box::new(UnusedImpl::<End>::new()),
+        Box::new(UnusedImpl::<Invariant<String, End>>::new()),
+        Box::new(UnusedImpl::<Covariant<Rc<u8>, End>>::new()),
+        Box::new(UnusedImpl::<Contravariant<*const u8, End>>::new()),
+    ];
+
+    let debugs: Vec<String> = markers.iter().map(|m| format!("{:#?}", m)).collect();
+    assert_eq!(
+        debugs,
+        [
+            "Unused",
+            "Unused(invariant)",
+            "Unused(covariant)",
+            "Unused(contravariant)"
+        ]
+    );
+
+    let arities: Vec<usize> = markers.iter().map(|m| m.arity()).collect();
+    assert_eq!(arities, [0, 1, 1, 1]);
+}
+
+#[test]
+fn lifetime() {
+    fn shorten<'a>(unused: Unused!(&'static str: covariant)) -> Unused!(&'a str: covariant) {
+        crate::shorten_covariant(unused)
+    }
+
+    fn lengthen(unused: Unused!(&str: contravariant)) -> Unused!(&'static str: contravariant) {
+        crate::lengthen_contravariant(unused)
+    }
+
+    let covariant: Unused!(&str: covariant) = shorten(Unused);
+    let contravariant = lengthen(Unused);
+    assert_eq!(
+        format!("{:#?} {:#?}", covariant, contravariant),
+        "Unused(covariant) Unused(contravariant)"
+    );
+}
+
+/// An invariant `&'long T` may be both read and written as a `&'long T`.
+/// Shortening it would allow a `&'short T` to be written where a `&'long T`
+/// is expected, and lengthening it would allow a `&'short T` to be read as a
+/// `&'long T`. Neither is done implicitly:
+///
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// fn shorten<'a>(unused: Unused!(&'static str)) -> Unused!(&'a str) {
+///     unused
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// fn lengthen<'a>(unused: Unused!(&'a str)) -> Unused!(&'static str) {
+///     unused
+/// }
+/// ```
+///
+/// Nor with the functions for covariant and contravariant lifetimes:
+///
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// fn shorten<'a>(unused: Unused!(&'static str)) -> Unused!(&'a str) {
+///     unused::shorten_covariant(unused)
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// fn lengthen<'a>(unused: Unused!(&'a str)) -> Unused!(&'static str) {
+///     unused::lengthen_contravariant(unused)
+/// }
+/// ```
+///
+/// Nor with `CoerceTo`:
+///
+/// ```compile_fail
+/// use unused::{CoerceTo, Unused};
+///
+/// fn shorten<'a>(unused: Unused!(&'static str)) -> Unused!(&'a str) {
+///     unused.coerce()
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::{CoerceTo, Unused};
+///
+/// fn lengthen<'a>(unused: Unused!(&'a str)) -> Unused!(&'static str) {
+///     unused.coerce()
+/// }
+/// ```
+pub fn _invariant_lifetimes_cannot_be_changed_safely() {}
+
+#[test]
+fn coerce() {
+    fn _covariant<'a, 'b: 'a>(unused: Unused!(&'b str: covariant)) -> Unused!(&'a str: covariant) {
+        crate::coerce_covariant(unused)
+    }
+
+    fn _contravariant<'a, 'b: 'a>(
+        unused: Unused!(&'a [u8]: contravariant),
+    ) -> Unused!(&'b [u8]: contravariant) {
+        crate::coerce_contravariant(unused)
+    }
+
+    let unused: Unused!(&'static str) = Unused;
+    let coerced: Unused!(String: covariant, u8: contravariant) =
+        unsafe { crate::coerce_variance(unused) };
+    assert_eq!(coerced, Unused);
+
+    // Converting `Unused`s stored in a collection must not read or write any memory,
+    // which Miri checks when running the tests.
+    let mut values: Vec<Unused!(&'static str)> = vec![Unused; 4];
+    let coerced: Vec<Unused!(u8: covariant)> = values
+        .drain(..)
+        .map(|unused| unsafe { crate::coerce_variance(unused) })
+        .collect();
+    assert_eq!(coerced.len(), 4);
+
+    fn _coerce_to_covariant<'a, 'b: 'a>(
+        unused: Unused!(&'b str: covariant),
+    ) -> Unused!(&'a str: covariant) {
+        crate::coerce_to(unused)
+    }
+
+    fn _coerce_to_contravariant<'a, 'b: 'a>(
+        unused: Unused!(&'a str: contravariant),
+    ) -> Unused!(&'b str: contravariant) {
+        crate::coerce_to(unused)
+    }
+
+    let invariant: Unused!(Rc<u8>) = Unused;
+    let covariant: Unused!(Rc<u8>: covariant) = crate::coerce_to(invariant);
+    let contravariant: Unused!(Rc<u8>: contravariant) = crate::coerce_to(invariant);
+    assert_eq!(
+        format!("{:#?} {:#?}", covariant, contravariant),
+        "Unused(covariant) Unused(contravariant)"
+    );
+}
+
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// fn shorten<'a>(
+///     unused: Unused!(&'static str: contravariant),
+/// ) -> Unused!(&'a str: contravariant) {
+///     unused::coerce_to(unused)
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// let unused: Unused!(String: contravariant) = Unused;
+/// let covariant: Unused!(String: covariant) = unused::coerce_to(unused);
+/// ```
+pub fn _coerce_to_disallowed() {}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn question_mark() {
+    fn from_result(result: Result<u8, &str>) -> Unused!(u8) {
+        let _ = result?;
+        Unused
+    }
+
+    fn from_option(option: Option<u8>) -> Unused!(u8: covariant) {
+        let _ = option?;
+        Unused
+    }
+
+    fn from_unused(unused: Unused!(u8)) -> Unused!(u8) {
+        unused?
+    }
+
+    assert_eq!(from_result(Ok(1)), Unused);
+    assert_eq!(from_result(Err("error")), Unused);
+    assert_eq!(from_option(None), Unused);
+    assert_eq!(from_unused(Unused), Unused);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn step() {
+    use core::iter::Step;
+
+    let unused = <Unused!(u8)>::default();
+    assert_eq!((unused..=unused).count(), 1);
+    assert_eq!((unused..unused).count(), 0);
+    assert_eq!((unused..=unused).step_by(2).count(), 1);
+    assert_eq!((unused..=unused).rev().count(), 1);
+    assert_eq!(Step::steps_between(&unused, &unused), (0, Some(0)));
+    assert_eq!(Step::forward_checked(unused, 0), Some(Unused));
+    assert_eq!(Step::forward_checked(unused, 1), None);
+    assert_eq!(Step::backward_checked(unused, 1), None);
+}
+
+#[test]
+fn phantom_bottom() {
+    use core::convert::Infallible;
+
+    use crate::PhantomBottom;
+
+    fn send_sync<T: Send + Sync>() {}
+
+    send_sync::<PhantomBottom>();
+    assert_eq!(core::mem::size_of::<PhantomBottom>(), 0);
+    assert_eq!(PhantomBottom::ARITY, 0);
+
+    fn from_result(result: Result<PhantomBottom, Infallible>) -> PhantomBottom {
+        result.unwrap_or_else(PhantomBottom::from)
+    }
+
+    assert_eq!(from_result(Ok(Unused)), Unused);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn phantom_bottom_from_never() {
+    use crate::PhantomBottom;
+
+    fn from_result(result: Result<PhantomBottom, !>) -> PhantomBottom {
+        result.unwrap_or_else(PhantomBottom::from)
+    }
+
+    assert_eq!(from_result(Ok(Unused)), Unused);
+}
+
+#[test]
+fn brand() {
+    use crate::{brand_token, Brand, End};
+
+    enum IdA {}
+    enum IdB {}
+
+    fn send_sync<T: Send + Sync>() {}
+
+    send_sync::<Brand<End, IdA>>();
+    send_sync::<Brand<crate::Invariant<Rc<u8>, End>, IdB>>();
+    assert_eq!(core::mem::size_of::<Brand<End, IdA>>(), 0);
+    assert_ne!(
+        core::any::TypeId::of::<Brand<End, IdA>>(),
+        core::any::TypeId::of::<Brand<End, IdB>>()
+    );
+
+    let brand: Brand<End, IdA> = Brand::new();
+    let unused: Unused!(IdA) = brand.into_unused();
+    assert_eq!(unused, Unused);
+
+    fn same<Id>(a: Brand<End, Id>, b: Brand<End, Id>) -> bool {
+        a == b
+    }
+
+    assert!(brand_token!(|brand| same(brand, brand)));
+    assert_eq!(brand_token!(|brand| core::mem::size_of_val(&brand)), 0);
+}
+
+/// ```compile_fail
+/// use unused::{Brand, End};
+///
+/// enum IdA {}
+/// enum IdB {}
+///
+/// let a: Brand<End, IdA> = Brand::new();
+/// let b: Brand<End, IdB> = a;
+/// ```
+///
+/// ```compile_fail
+/// use unused::brand_token;
+///
+/// let a = brand_token!(|brand| brand);
+/// let b = brand_token!(|brand| brand);
+/// assert_eq!(a, b);
+/// ```
+pub fn _brands_are_distinct() {}
+
+#[test]
+fn type_eq() {
+    use crate::TypeEq;
+
+    /// An index into a heterogeneous list `(A, B, C)`, which carries a proof
+    /// that the element at that index is a `T`.
+    enum Index<A, B, C, T> {
+        First(TypeEq<A, T>),
+        Second(TypeEq<B, T>),
+        Third(TypeEq<C, T>),
+    }
+
+    fn get<A, B, C, T>(list: (A, B, C), index: Index<A, B, C, T>) -> T {
+        match index {
+            Index::First(eq) => eq.coerce(list.0),
+            Index::Second(eq) => eq.coerce(list.1),
+            Index::Third(eq) => eq.coerce(list.2),
+        }
+    }
+
+    let list = || (1u8, String::from("two"), Rc::new(3u32));
+    assert_eq!(get(list(), Index::First(TypeEq::refl())), 1);
+    assert_eq!(get(list(), Index::Second(TypeEq::refl())), "two");
+    let third = get(list(), Index::Third(TypeEq::refl()));
+    assert_eq!((*third, Rc::strong_count(&third)), (3, 1));
+
+    let eq: TypeEq<Vec<u8>, Vec<u8>> = TypeEq::refl();
+    let eq = eq.sym().trans(eq).trans(TypeEq::refl());
+    assert_eq!(eq.coerce(vec![1, 2]), [1, 2]);
+    assert_eq!(eq, eq.clone());
+    assert_eq!(format!("{:?}", eq), "TypeEq");
+
+    let _: TypeEq<str, str> = TypeEq::refl();
+    assert_eq!(core::mem::size_of::<TypeEq<String, String>>(), 0);
+}
+
+/// ```compile_fail
+/// use unused::TypeEq;
+///
+/// fn get<A, B, T>(list: (A, B), eq: TypeEq<A, T>) -> T {
+///     eq.coerce(list.0)
+/// }
+///
+/// let value: String = get((1u8, String::new()), TypeEq::refl());
+/// ```
+///
+/// ```compile_fail
+/// use unused::TypeEq;
+///
+/// fn shorten<'a>(eq: TypeEq<&'static str, &'static str>) -> TypeEq<&'a str, &'static str> {
+///     eq
+/// }
+/// ```
+pub fn _type_eq_requires_equal_types() {}
+
+#[test]
+fn capability() {
+    use crate::Capability;
+
+    enum NetworkAccess {}
+
+    fn send_sync<T: Send + Sync>() {}
+
+    fn connect(_cap: &Capability<NetworkAccess>) -> bool {
+        true
+    }
+
+    // SAFETY: tests may access the network.
+    let network = unsafe { Capability::<NetworkAccess>::new() };
+    assert!(connect(&network));
+    assert_eq!(format!("{:?}", network), "Capability");
+    assert_eq!(core::mem::size_of::<Capability<NetworkAccess>>(), 0);
+    send_sync::<Capability<Rc<u8>>>();
+}
+
+/// ```compile_fail
+/// use unused::Capability;
+///
+/// enum NetworkAccess {}
+/// enum DiskAccess {}
+///
+/// fn connect(_cap: &Capability<NetworkAccess>) {}
+///
+/// let disk = unsafe { Capability::<DiskAccess>::new() };
+/// connect(&disk);
+/// ```
+///
+/// ```compile_fail
+/// use unused::Capability;
+///
+/// enum NetworkAccess {}
+///
+/// let network: Capability<NetworkAccess> = Default::default();
+/// ```
+///
+/// ```compile_fail
+/// use unused::Capability;
+///
+/// enum NetworkAccess {}
+///
+/// fn forge(cap: &Capability<NetworkAccess>) -> Capability<NetworkAccess> {
+///     cap.clone()
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::Capability;
+///
+/// enum NetworkAccess {}
+///
+/// let network = Capability::<NetworkAccess>::new();
+/// ```
+pub fn _capabilities_cannot_be_forged() {}
+
+#[cfg(feature = "std")]
+#[test]
+fn mirror() {
+    use core::any::{Any, TypeId};
+    use std::collections::HashMap;
+
+    use crate::{Mirror, TypeMap};
+
+    let mut map: HashMap<TypeId, Box<dyn Any>> = TypeMap::new();
+    assert!(Mirror::<u8>::insert(&mut map));
+    assert!(Mirror::<String>::insert(&mut map));
+    assert!(Mirror::<Vec<u8>>::insert(&mut map));
+    assert!(!Mirror::<u8>::insert(&mut map));
+    assert_eq!(map.len(), 3);
+
+    assert_eq!(Mirror::<u8>::lookup(&map), Some(&Mirror::new()));
+    assert_eq!(Mirror::<String>::lookup(&map), Some(&Mirror::new()));
+    assert_eq!(Mirror::<Vec<u8>>::lookup(&map), Some(&Mirror::new()));
+    assert_eq!(Mirror::<Vec<u16>>::lookup(&map), None);
+    assert_eq!(Mirror::<str>::lookup(&map), None);
+
+    assert_ne!(TypeId::of::<Mirror<u8>>(), TypeId::of::<Mirror<String>>());
+    assert_eq!(core::mem::size_of::<Mirror<String>>(), 0);
+    assert_eq!(
+        core::mem::size_of_val(&*map[&TypeId::of::<Mirror<u8>>()]),
+        0
+    );
+}
+
+#[test]
+fn type_tag() {
+    use core::any::TypeId;
+    use core::hash::Hasher;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+
+    use crate::TypeTag;
+
+    assert_eq!(TypeTag::<u8>::type_id(), TypeId::of::<u8>());
+    assert_eq!(TypeTag::<str>::type_id(), TypeId::of::<str>());
+    assert_eq!(TypeTag::<u8>::type_name(), "u8");
+    assert_eq!(format!("{:?}", TypeTag::<u8>::new()), r#"TypeTag("u8")"#);
+
+    assert_eq!(TypeTag::<u8>::new(), TypeTag::<u8>::new());
+    assert!(TypeTag::<u8>::new().same_type(&TypeTag::<u8>::new()));
+    assert!(!TypeTag::<u8>::new().same_type(&TypeTag::<u16>::new()));
+    assert!(!TypeTag::<&'static str>::new().same_type(&TypeTag::<str>::new()));
+
+    fn hash<T: ?Sized>(tag: TypeTag<T>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        tag.hash(&mut hasher);
+        hasher.finish()
+    }
+    let nothing = DefaultHasher::new().finish();
+    assert_eq!(hash(TypeTag::<u8>::new()), nothing);
+    assert_eq!(hash(TypeTag::<&str>::new()), nothing);
+
+    let tags: HashSet<TypeTag<Vec<u8>>> =
+        vec![TypeTag::new(), TypeTag::new()].into_iter().collect();
+    assert_eq!(tags.len(), 1);
+    assert_eq!(core::mem::size_of::<TypeTag<String>>(), 0);
+
+    // The type of a tag is inferred from the other side of a comparison.
+    let tag = TypeTag::new();
+    assert_eq!(tag, TypeTag::<u8>::new());
+    assert_eq!(TypeTag::<u8>::new(), Default::default());
+}
+
+#[test]
+fn phantom_fn() {
+    use crate::{PhantomFn, PhantomFnMut, PhantomFnOnce};
+
+    fn _fn<'a>(f: PhantomFn<&'a str, &'static str>) -> PhantomFn<&'static str, &'a str> {
+        f
+    }
+
+    fn _fn_mut<'a>(f: PhantomFnMut<&'a str, &'a str>) -> PhantomFnMut<&'a str, &'a str> {
+        f
+    }
+
+    fn _fn_once<'a>(f: PhantomFnOnce<&'a str, &'static str>) -> PhantomFnOnce<&'a str, &'a str> {
+        f
+    }
+
+    fn send_sync<T: Send + Sync>() {}
+
+    send_sync::<PhantomFn<Rc<u8>, Cell<u8>>>();
+    send_sync::<PhantomFnMut<Rc<u8>, Cell<u8>>>();
+    send_sync::<PhantomFnOnce<Rc<u8>, Cell<u8>>>();
+    assert_eq!(
+        format!("{:#?}", <PhantomFnOnce<u8, u8>>::new()),
+        "Unused(invariant, covariant)"
+    );
+}
+
+/// ```compile_fail
+/// use unused::PhantomFnMut;
+///
+/// fn args<'a>(f: PhantomFnMut<&'a str, ()>) -> PhantomFnMut<&'static str, ()> {
+///     f
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::PhantomFnMut;
+///
+/// fn ret<'a>(f: PhantomFnMut<(), &'static str>) -> PhantomFnMut<(), &'a str> {
+///     f
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::PhantomFnOnce;
+///
+/// fn args<'a>(f: PhantomFnOnce<&'a str, ()>) -> PhantomFnOnce<&'static str, ()> {
+///     f
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use unused::PhantomFn;
+///
+/// fn args<'a>(f: PhantomFn<&'static str, ()>) -> PhantomFn<&'a str, ()> {
+///     f
+/// }
+/// ```
+pub fn _phantom_fn_variances() {}
+
+#[test]
+fn higher_kinded() {
+    use crate::{Functor, IdentityF, PairF, UnusedApply};
+
+    let identity: UnusedApply<IdentityF, u8> = Unused;
+    let identity = IdentityF::fmap(identity, u16::from);
+    let identity = IdentityF::fmap(identity, |value| value.to_string());
+    let _identity: Unused!(String: covariant) = identity;
+
+    fn fmap_twice<F, A, B, C>(
+        fa: UnusedApply<F, A>,
+        f: impl FnOnce(A) -> B,
+        g: impl FnOnce(B) -> C,
+    ) -> UnusedApply<F, C>
+    where
+        F: Functor
+            + crate::TypeConstructor<A>
+            + crate::TypeConstructor<B>
+            + crate::TypeConstructor<C>,
+    {
+        F::fmap(F::fmap(fa, f), g)
+    }
+
+    let pair: UnusedApply<PairF, &str> = Unused;
+    let pair = fmap_twice::<PairF, _, _, _>(pair, str::len, Rc::new);
+    let _pair: Unused!((Rc<usize>, Rc<usize>): covariant) = pair;
+
+    fn covariant<'a>(pair: UnusedApply<PairF, &'static str>) -> UnusedApply<PairF, &'a str> {
+        PairF::fmap(pair, |value| value)
+    }
+    assert_eq!(covariant(Unused), Unused);
+}
+
+#[test]
+fn sealed() {
+    use crate::sealed::{HasSealedToken, Sealed, SealedToken};
+
+    crate::sealed_token!(Seal);
+
+    trait Sides: HasSealedToken<Seal> {
+        fn sides(&self, sealed: Sealed<Self, Seal>) -> u32;
+    }
+
+    struct Square;
+
+    impl SealedToken<Square> for Seal {}
+
+    impl Sides for Square {
+        fn sides(&self, _sealed: Sealed<Self, Seal>) -> u32 {
+            4
+        }
+    }
+
+    fn send_sync<T: Send + Sync>() {}
+
+    send_sync::<Sealed<Rc<u8>, Seal>>();
+    assert_eq!(core::mem::size_of::<Sealed<[u8; 1024], Seal>>(), 0);
+    assert_eq!(Square.sides(Sealed::new(Seal)), 4);
+}
+
+#[test]
+fn build_hasher() {
+    use core::hash::{BuildHasher, Hash, Hasher};
+    use std::collections::HashMap;
+
+    use crate::NoopHasher;
+
+    let hasher = <Unused!(u8)>::default();
+    assert_eq!(BuildHasher::build_hasher(&hasher).finish(), 0);
+
+    let mut noop = hasher.build_hasher();
+    "data".hash(&mut noop);
+    noop.write_u64(u64::MAX);
+    assert_eq!(noop.finish(), 0);
+    assert_eq!(noop, NoopHasher);
+
+    let mut map: HashMap<&str, u8, Unused!(u8)> = HashMap::with_hasher(hasher);
+    map.insert("one", 1);
+    map.insert("two", 2);
+    assert_eq!(map.get("one"), Some(&1));
+    assert_eq!(map.get("two"), Some(&2));
+    assert_eq!(map.len(), 2);
+}
+
+#[test]
+fn waker() {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    let waker = <Unused!(u8)>::new().waker();
+    waker.wake_by_ref();
+
+    let mut cx = Context::from_waker(&waker);
+    let mut ready = core::future::ready(1);
+    assert_eq!(Pin::new(&mut ready).poll(&mut cx), Poll::Ready(1));
+
+    waker.wake();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn wake() {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll, Waker};
+    use std::sync::Arc;
+
+    let unused: Unused!(u8) = Unused;
+    let waker = Waker::from(Arc::new(unused));
+    waker.wake_by_ref();
+
+    let mut cx = Context::from_waker(&waker);
+    let mut ready = core::future::ready("ready");
+    assert_eq!(Pin::new(&mut ready).poll(&mut cx), Poll::Ready("ready"));
+
+    waker.wake();
+}
+
+#[test]
+fn cfg_variance() {
+    crate::cfg_variance! {
+        type Covariant<T> = T: [cfg(test)] covariant, [cfg(not(test))] contravariant;
+    }
+
+    crate::cfg_variance! {
+        type Contravariant<T> = T: [cfg(not(test))] covariant, [cfg(test)] contravariant;
+    }
+
+    crate::cfg_variance! {
+        type FirstMatch<T> = T: [cfg(all())] covariant, [cfg(all())] contravariant;
+    }
+
+    crate::cfg_variance! {
+        type NoMatch<T> = T: [cfg(any())] covariant, [cfg(not(all()))] contravariant;
+    }
+
+    fn shorten<'a>(unused: Covariant<&'static str>) -> Covariant<&'a str> {
+        unused
+    }
+
+    fn lengthen(unused: Contravariant<&str>) -> Contravariant<&'static str> {
+        unused
+    }
+
+    fn first_match<'a>(unused: FirstMatch<&'static str>) -> Unused!(&'a str: covariant) {
+        unused
+    }
+
+    fn no_match(unused: NoMatch<Rc<u8>>) -> Unused!(Rc<u8>) {
+        unused
+    }
+
+    auto_traits_are_implemented_by(shorten(Unused));
+    auto_traits_are_implemented_by(lengthen(Unused));
+    auto_traits_are_implemented_by(first_match(Unused));
+    auto_traits_are_implemented_by(no_match(Unused));
+}
+
+/// ```compile_fail
+/// unused::cfg_variance! {
+///     type Invariant<T> = T: [cfg(any())] covariant;
+/// }
+///
+/// fn shorten<'a>(unused: Invariant<&'static str>) -> Invariant<&'a str> {
+///     unused
+/// }
+/// ```
+pub fn _cfg_variance_defaults_to_invariant() {}
+
+#[test]
+fn builder() {
+    use crate::UnusedBuilder;
+
+    fn same<T>(value: T) -> T {
+        value
+    }
+
+    let empty = UnusedBuilder::new().build();
+    let _empty: crate::PhantomBottom = same(empty);
+
+    let built = UnusedBuilder::new()
+        .invariant::<u8>()
+        .covariant::<Rc<str>>()
+        .contravariant::<[u16]>()
+        .invariant::<&'static str>()
+        .build();
+    let unused: Unused!(u8, Rc<str>: covariant, [u16]: contravariant, &'static str) = same(built);
+    assert_eq!(
+        same::<Unused!(u8, Rc<str>: covariant, [u16]: contravariant, &str)>(built),
+        unused
+    );
+
+    const BUILDER: UnusedBuilder<crate::Invariant<u8, crate::End>> =
+        UnusedBuilder::new().invariant::<u8>();
+    assert_eq!(format!("{:?}", BUILDER), "UnusedBuilder(Unused)");
+}
+
+#[allow(dead_code)]
+mod verify {
+    use crate::{verify_unused, Unused};
+
+    struct Covariant<T> {
+        unused: Unused!(T: covariant),
+    }
+
+    struct Contravariant<T> {
+        unused: Unused!(T: contravariant),
+    }
+
+    struct Mixed<A, B, C> {
+        unused: Unused!(A, B: covariant, C: contravariant),
+    }
+
+    verify_unused!(Covariant<T> is_covariant_over T);
+    verify_unused!(Contravariant<T> is_contravariant_over T);
+    verify_unused!(Mixed<A, B, C> is_covariant_over B);
+    verify_unused!(Mixed<A, B, C> is_contravariant_over C);
+}
+
+/// ```compile_fail
+/// use unused::{verify_unused, Unused};
+///
+/// struct Invariant<T> {
+///     unused: Unused!(T: invariant),
+/// }
+///
+/// verify_unused!(Invariant<T> is_covariant_over T);
+/// ```
+///
+/// ```compile_fail
+/// use unused::{verify_unused, Unused};
+///
+/// struct Invariant<T> {
+///     unused: Unused!(T: invariant),
+/// }
+///
+/// verify_unused!(Invariant<T> is_contravariant_over T);
+/// ```
+///
+/// ```compile_fail
+/// use unused::{verify_unused, Unused};
+///
+/// struct Mixed<A, B> {
+///     unused: Unused!(A: covariant, B: contravariant),
+/// }
+///
+/// verify_unused!(Mixed<A, B> is_covariant_over B);
+/// ```
+///
+/// ```compile_fail
+/// use unused::{verify_unused, Unused};
+///
+/// struct Covariant<T> {
+///     unused: Unused!(T: covariant),
+/// }
+///
+/// verify_unused!(Covariant<T> is_contravariant_over T);
+/// ```
+///
+/// ```compile_fail
+/// use unused::{verify_unused, Unused};
+///
+/// struct Contravariant<T> {
+///     unused: Unused!(T: contravariant),
+/// }
+///
+/// verify_unused!(Contravariant<T> is_covariant_over T);
+/// ```
+///
+/// ```compile_fail
+/// use unused::{verify_unused, Unused};
+///
+/// pub struct Covariant<T> {
+///     unused: Unused!(T: covariant),
+/// }
+///
+/// verify_unused!(Covariant<T> is_invariant_over T);
+/// ```
+pub fn _verify_unused_rejects_wrong_variance() {}
+
+#[test]
+fn cow() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    use crate::UnusedCow;
+
+    fn shorten<'a>(cow: UnusedCow<'static, str>) -> UnusedCow<'a, str> {
+        cow
+    }
+
+    let owned: UnusedCow<'static, Rc<String>> = UnusedCow::default();
+    auto_traits_are_implemented_by(owned);
+    assert!(owned.is_owned());
+    assert!(owned.clone().is_owned());
+    assert!(!owned.is_borrowed());
+
+    let borrowed: UnusedCow<'static, Rc<String>> = UnusedCow::borrowed();
+    assert!(borrowed.clone().is_borrowed());
+    assert!(borrowed.into_owned().is_owned());
+    assert_eq!(borrowed, owned);
+
+    let mut hasher = DefaultHasher::new();
+    owned.hash(&mut hasher);
+    assert_eq!(hasher.finish(), DefaultHasher::new().finish());
+
+    assert!(shorten(UnusedCow::borrowed()).is_borrowed());
+    assert_eq!(format!("{:?}", owned), "Owned(Unused)");
+    assert_eq!(format!("{:?}", borrowed), "Borrowed(Unused)");
+    assert_eq!(core::mem::size_of::<UnusedCow<'static, [u8]>>(), 1);
+}
+
+#[test]
+fn std_cow() {
+    use std::borrow::{Cow, ToOwned};
+
+    let unused = <Unused!(u8)>::default();
+    let borrowed: Cow<'_, Unused!(u8)> = Cow::Borrowed(&unused);
+    let owned: Cow<'_, Unused!(u8)> = Cow::Owned(Unused);
+
+    assert_eq!(unused.to_owned(), Unused);
+    assert_eq!(*borrowed, Unused);
+    assert_eq!(borrowed.clone().into_owned(), Unused);
+    assert_eq!(owned.into_owned(), Unused);
+
+    let mut cow: Cow<'_, Unused!(String: covariant)> = Cow::Borrowed(&Unused);
+    *cow.to_mut() = Unused;
+    assert!(matches!(cow, Cow::Owned(Unused)));
+}
+
+#[test]
+fn termination() {
+    use crate::Termination;
+
+    let unused: Unused!(()) = Unused;
+    assert_eq!(unused.report(), 0);
+    assert_eq!(Termination::report(<Unused!(Rc<u8>: covariant)>::new()), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn process_termination() {
+    use std::process::{ExitCode, Termination};
+
+    let unused: Unused!(()) = Unused;
+    assert_eq!(unused.report(), ExitCode::SUCCESS);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn allocator() {
+    use core::alloc::{AllocError, Allocator, Layout};
+
+    enum AllocTag {}
+
+    let allocator: Unused!(AllocTag) = Unused;
+    assert_eq!(allocator.allocate(Layout::new::<u64>()), Err(AllocError));
+    assert_eq!(allocator.allocate(Layout::new::<()>()), Err(AllocError));
+    assert_eq!(
+        allocator.allocate_zeroed(Layout::new::<u8>()),
+        Err(AllocError)
+    );
+
+    let mut vec: Vec<u8, Unused!(AllocTag)> = Vec::new_in(allocator);
+    assert!(vec.try_reserve(1).is_err());
+    assert_eq!(vec.capacity(), 0);
+    assert_eq!(core::mem::size_of_val(vec.allocator()), 0);
+    assert_eq!(
+        core::mem::size_of::<Vec<u8, Unused!(AllocTag)>>(),
+        core::mem::size_of::<Vec<u8>>()
+    );
+
+    let zero_sized: Vec<(), Unused!(AllocTag)> = Vec::with_capacity_in(16, allocator);
+    assert!(zero_sized.capacity() >= 16);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn call() {
+    fn call_fn(f: impl Fn()) {
+        f()
+    }
+
+    fn call_fn_mut(mut f: impl FnMut(u8) -> Unused!(u8)) -> Unused!(u8) {
+        f(1)
+    }
+
+    fn call_fn_once(f: impl FnOnce(&str) -> Unused!(u8)) -> Unused!(u8) {
+        f("argument")
+    }
+
+    let f: Unused!(u8) = Unused;
+    #[allow(clippy::unit_cmp)]
+    {
+        assert_eq!(f(), ());
+    }
+    assert_eq!(f(1), Unused);
+    call_fn(f);
+    assert_eq!(call_fn_mut(f), Unused);
+    assert_eq!(call_fn_once(f), Unused);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn future() {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll, Waker};
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use crate::{PendingForever, ReadyUnused};
+
+    fn poll<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+        Pin::new(future).poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    let mut unused = <Unused!(u8)>::default();
+    for _ in 0..3 {
+        assert!(poll(&mut unused).is_pending());
+    }
+
+    struct CountingWaker(AtomicUsize);
+
+    impl std::task::Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let mut pending: PendingForever<Rc<u8>> = Unused;
+    assert!(Pin::new(&mut pending)
+        .poll(&mut Context::from_waker(&waker))
+        .is_pending());
+    assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+
+    let mut ready = ReadyUnused::<Rc<u8>>::new();
+    assert_eq!(poll(&mut ready), Poll::Ready(()));
+    assert_eq!(core::mem::size_of::<ReadyUnused<String>>(), 0);
+    auto_traits_are_implemented::<ReadyUnused<Rc<u8>>>();
+}
+
+#[test]
+fn collect_and_extend() {
+    assert_eq!((0..100).collect::<Unused!(usize)>(), Unused);
+
+    let mut consumed = 0;
+    let collected: Unused!(usize) = (0..100).inspect(|_| consumed += 1).collect();
+    assert_eq!(collected, Unused);
+    assert_eq!(consumed, 100);
+
+    let mut unused: Unused!(usize) = Unused;
+    unused.extend((0..100).inspect(|_| consumed += 1));
+    assert_eq!(consumed, 200);
+}
+
+#[test]
+fn sum_and_product() {
+    let unused: Unused!(u8) = Unused;
+    let values = [unused; 5];
+
+    assert_eq!(values.iter().copied().sum::<Unused!(u8)>(), Unused);
+    assert_eq!(values.iter().sum::<Unused!(u8)>(), Unused);
+    assert_eq!(values.iter().copied().product::<Unused!(u8)>(), Unused);
+    assert_eq!(values.iter().product::<Unused!(u8)>(), Unused);
+    assert_eq!(
+        core::iter::empty::<Unused!(u8)>().sum::<Unused!(u8)>(),
+        Unused
+    );
+}
+
+#[test]
+fn list_macros() {
+    use crate::{UnusedContravariantList, UnusedCovariantList, UnusedList};
+
+    type T = Rc<UnsafeCell<u8>>;
+
+    let one: UnusedList![T] = Unused;
+    let one: Unused!(T) = one;
+    let eight: UnusedList![T, u8, u16, u32, u64, u128, i8, T] = Unused;
+    let eight: Unused!(T, u8, u16, u32, u64, u128, i8, T) = eight;
+    let sixteen: UnusedList![T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T] = Unused;
+    auto_traits_are_implemented_by(one);
+    auto_traits_are_implemented_by(eight);
+    auto_traits_are_implemented_by(sixteen);
+    assert_eq!(core::mem::size_of_val(&sixteen), 0);
+
+    let one: UnusedCovariantList![T] = Unused;
+    let one: Unused!(T: covariant) = one;
+    let eight: UnusedCovariantList![T, T, T, T, T, T, T, T] = Unused;
+    let sixteen: UnusedCovariantList![T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T] = Unused;
+    auto_traits_are_implemented_by(one);
+    auto_traits_are_implemented_by(eight);
+    auto_traits_are_implemented_by(sixteen);
+    assert_eq!(core::mem::size_of_val(&sixteen), 0);
+
+    let one: UnusedContravariantList![T] = Unused;
+    let one: Unused!(T: contravariant) = one;
+    let eight: UnusedContravariantList![T, T, T, T, T, T, T, T] = Unused;
+    let sixteen: UnusedContravariantList![T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T] = Unused;
+    auto_traits_are_implemented_by(one);
+    auto_traits_are_implemented_by(eight);
+    auto_traits_are_implemented_by(sixteen);
+    assert_eq!(core::mem::size_of_val(&sixteen), 0);
+
+    assert_eq!(
+        <UnusedList![
+            T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+            T, T, T
+        ]>::ARITY,
+        32
+    );
+
+    fn _covariant<'a>(
+        unused: UnusedCovariantList![&'static str, &'static [u8]],
+    ) -> UnusedCovariantList![&'a str, &'a [u8]] {
+        unused
+    }
+
+    fn _contravariant<'a>(
+        unused: UnusedContravariantList![&'a str, &'a [u8]],
+    ) -> UnusedContravariantList![&'static str, &'static [u8]] {
+        unused
+    }
+}
+
+#[test]
+fn head_and_tail() {
+    use crate::{head, tail, Contravariant, Covariant, End, Invariant, UnusedHead, UnusedTail};
+
+    type Chain = Invariant<u8, Covariant<u16, Contravariant<u32, End>>>;
+
+    let unused: crate::UnusedImpl<Chain> = Unused;
+
+    let first: Unused!(u8) = head(unused);
+    let rest: Unused!(u16: covariant, u32: contravariant) = tail(unused);
+    let second: Unused!(u16: covariant) = head(rest);
+    let rest: Unused!(u32: contravariant) = tail(rest);
+    let third: Unused!(u32: contravariant) = head(rest);
+    let rest: crate::UnusedImpl<End> = tail(rest);
+    assert_eq!(
+        format!("{:#?} {:#?} {:#?} {:#?}", first, second, third, rest),
+        "Unused(invariant) Unused(covariant) Unused(contravariant) Unused"
+    );
+
+    let first: crate::UnusedImpl<UnusedHead<Chain>> = first;
+    let second: crate::UnusedImpl<UnusedHead<UnusedTail<Chain>>> = second;
+    let rest: crate::UnusedImpl<UnusedTail<UnusedTail<UnusedTail<Chain>>>> = rest;
+    assert_eq!((first, second, rest), (Unused, Unused, Unused));
+
+    fn arity<T: crate::UnusedList>() -> usize
+    where
+        T::Tail: crate::UnusedList,
+    {
+        crate::UnusedImpl::<T::Head>::ARITY + crate::UnusedImpl::<T::Tail>::ARITY
+    }
+    assert_eq!(arity::<Chain>(), 3);
+}
+
+#[test]
+fn concat() {
+    let a: Unused!(u8) = Unused;
+    let b: Unused!(u16) = Unused;
+    let ab: Unused!(u8, u16) = crate::concat(a, b);
+
+    let c: Unused!(Rc<str>: covariant, *const u8: contravariant) = Unused;
+    let abc: Unused!(u8, u16, Rc<str>: covariant, *const u8: contravariant) = crate::concat(ab, c);
+    let abc: Unused!(u8, u16, Rc<str>: covariant, *const u8: contravariant) =
+        crate::concat(a, crate::concat(b, c)) + abc;
+    auto_traits_are_implemented_by(abc);
+    assert_eq!(core::mem::size_of_val(&abc), 0);
+
+    let empty: crate::UnusedImpl<crate::End> = Unused;
+    let same: Unused!(u8) = crate::concat(empty, a);
+    let same: Unused!(u8) = crate::concat(same, empty);
+    assert_eq!(same, a);
+}
+
+#[test]
+fn push_front_and_back() {
+    use crate::{Contravariant, Covariant, End, Invariant, PushBack, PushFront, UnusedImpl};
+
+    type Front = PushFront<Covariant<u8, End>, Invariant<u16, End>>;
+    type Back = PushBack<Contravariant<u32, End>, Front>;
+
+    assert_eq!(UnusedImpl::<Front>::ARITY, 2);
+    assert_eq!(UnusedImpl::<Back>::ARITY, 3);
+
+    let back: UnusedImpl<Back> = Unused;
+    let back: Unused!(u8: covariant, u16, u32: contravariant) = back;
+    assert_eq!(back, Unused);
+
+    let empty: UnusedImpl<PushBack<End, End>> = Unused;
+    let empty: UnusedImpl<End> = empty;
+    assert_eq!(empty, Unused);
+}
+
+#[test]
+fn zip() {
+    use crate::{Contravariant, Covariant, End, Zip};
+
+    let covariant: Unused!(u8: covariant, u16: covariant) = Unused;
+    let contravariant: Unused!(i8: contravariant, i16: contravariant) = Unused;
+    let zipped: Unused!(
+        u8: covariant,
+        i8: contravariant,
+        u16: covariant,
+        i16: contravariant,
+    ) = crate::zip(covariant, contravariant);
+    assert_eq!(
+        format!("{:#?}", zipped),
+        "Unused(covariant, contravariant, covariant, contravariant)"
+    );
+
+    let zipped: Unused!(i8: contravariant, u8: covariant, i16: contravariant, u16: covariant) =
+        crate::zip(contravariant, covariant);
+    assert_eq!(zipped, Unused);
+
+    let longer: Unused!(u8, u16, u32) = Unused;
+    let zipped: Unused!(u8, i8: contravariant, u16, i16: contravariant, u32) =
+        crate::zip(longer, contravariant);
+    assert_eq!(zipped, Unused);
+
+    let zipped: crate::UnusedImpl<Zip<Covariant<u8, End>, Contravariant<i8, End>>> =
+        crate::zip(crate::head(covariant), crate::head(contravariant));
+    let zipped: Unused!(u8: covariant, i8: contravariant) = zipped;
+    assert_eq!(zipped, Unused);
+}
+
+/// ```compile_fail
+/// use unused::Unused;
+///
+/// struct Start;
+/// struct Running;
+///
+/// struct StateMachine<State> {
+///     unused: Unused!(State: covariant),
+/// }
+///
+/// fn start(_machine: StateMachine<Start>) -> StateMachine<Running> {
+///     StateMachine { unused: Unused }
+/// }
+///
+/// let running: StateMachine<Running> = StateMachine { unused: Unused };
+/// start(running);
+/// ```
+pub fn _type_state_transition_requires_state() {}
+
+#[test]
+fn const_generics() {
+    use crate::{UnusedConst, UnusedMixed};
+
+    struct Foo<T, const N: usize, const B: bool> {
+        _unused: UnusedMixed!(T: covariant, const N: usize, const B: bool, T),
+    }
+
+    assert_eq!(core::mem::size_of::<UnusedConst<42>>(), 0);
+    assert_eq!(format!("{:?}", UnusedConst::<42>), "UnusedConst");
+    auto_traits_are_implemented::<UnusedConst<42>>();
+    auto_traits_are_implemented::<Foo<Rc<UnsafeCell<u8>>, 1, true>>();
+    assert_eq!(core::mem::size_of::<Foo<String, 1, true>>(), 0);
+
+    let unused: UnusedConst!(42usize, true: bool, 'a': char, { -1 }: i8) = Unused;
+    let unused: Unused!(
+        UnusedConst<42>,
+        crate::__const::bool<true>,
+        crate::__const::char<'a'>,
+        crate::__const::i8<-1>,
+    ) = unused;
+    assert_eq!(unused, Unused);
+
+    let mixed: UnusedMixed!(u8, const 3: u8, u16: contravariant) = Unused;
+    assert_eq!(
+        format!("{:#?}", mixed),
+        "Unused(invariant, invariant, contravariant)"
+    );
+}
+
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+///
+/// use unused::Unused;
+///
+/// <Unused!(u8)>::new();
+/// ```
+pub fn _new_is_must_use() {}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn schemars() {
+    use schemars::{schema_for, JsonSchema};
+
+    type UnusedString = Unused!(String);
+
+    #[derive(JsonSchema)]
+    struct Field {
+        _unused: UnusedString,
+    }
+
+    #[derive(JsonSchema)]
+    struct Skipped {
+        #[schemars(skip)]
+        _unused: UnusedString,
+    }
+
+    assert_eq!(schema_for!(Unused!(u8)), schema_for!(()));
+    assert_eq!(
+        schema_for!(Field).get("properties"),
+        Some(&serde_json::json!({ "_unused": { "type": "null" } })),
+    );
+    assert_eq!(schema_for!(Skipped).get("type"), Some(&"object".into()));
+    assert_eq!(schema_for!(Skipped).get("properties"), None);
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn rkyv() {
+    use rkyv::rancor::Error;
+    use rkyv::{Archive, Deserialize, Serialize};
+
+    type UnusedU8 = Unused!(u8);
+
+    #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+    struct Foo {
+        value: u32,
+        unused: UnusedU8,
+    }
+
+    let foo = Foo {
+        value: 5,
+        unused: Unused,
+    };
+    let bytes = rkyv::to_bytes::<Error>(&foo).unwrap();
+    assert_eq!(bytes.len(), 4);
+
+    let archived = rkyv::access::<ArchivedFoo, Error>(&bytes).unwrap();
+    assert_eq!(archived.value, 5);
+    assert_eq!(archived.unused, Unused);
+
+    let deserialized = rkyv::deserialize::<Foo, Error>(archived).unwrap();
+    assert_eq!(deserialized, foo);
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn bincode() {
+    use bincode::{config, Decode, Encode};
+
+    type UnusedU8 = Unused!(u8);
+
+    #[derive(Encode, Decode, Debug, PartialEq)]
+    struct Foo {
+        value: u8,
+        unused: UnusedU8,
+    }
+
+    let config = config::standard();
+    let unused: Unused!(u8) = Unused;
+    assert!(bincode::encode_to_vec(unused, config).unwrap().is_empty());
+
+    let foo = Foo { value: 5, unused };
+    let bytes = bincode::encode_to_vec(&foo, config).unwrap();
+    assert_eq!(bytes, [5]);
+    assert_eq!(
+        bincode::decode_from_slice(&bytes, config).unwrap(),
+        (foo, 1)
+    );
+
+    // Decoding an `Unused` reads nothing, leaving any other bytes unread.
+    let (decoded, read): (Unused!(u8), _) = bincode::decode_from_slice(&[1, 2], config).unwrap();
+    assert_eq!((decoded, read), (Unused, 0));
+    let (decoded, read): (Unused!(u8), _) =
+        bincode::borrow_decode_from_slice(&[1, 2], config).unwrap();
+    assert_eq!((decoded, read), (Unused, 0));
+}
+
+/// Unknown variances are rejected.
+///
+/// ```compile_fail
+/// let _: unused::Unused!(u8: bicontravariant) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// fn f<'a>(_: unused::Unused!('a: bicovariant)) {}
+/// ```
+///
+/// Including `bivariant`, as Rust cannot express a type that is both
+/// covariant and contravariant:
+///
+/// ```compile_fail
+/// let _: unused::Unused!(u8: bivariant) = unused::Unused;
+/// ```
+pub fn _unknown_variances() {}
+
+/// Malformed invocations of `Unused!` are rejected.
+///
+/// ```compile_fail
+/// let _: unused::Unused!() = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::Unused!(,) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::Unused!(u8,,) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::Unused!(, u8) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::Unused!(u8:) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::Unused!(: covariant) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::Unused!(u8: covariant: invariant) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::Unused!(u8 covariant) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::Unused!(u8; u16) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::Unused!(1) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// fn f<'a>(_: unused::Unused!(&'a)) {}
+/// ```
+pub fn _malformed_unused_macro() {}
+
+/// Only the types created by `Unused!` can be used in `Unused`, and the
+/// traits they implement cannot be implemented downstream.
+///
+/// ```compile_fail
+/// let _: unused::Unused<u8> = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// #[derive(Clone, Copy)]
+/// struct Custom;
+///
+/// impl unused::UnusedInner for Custom {}
+/// ```
+///
+/// ```compile_fail
+/// use unused::End;
+///
+/// #[derive(Clone, Copy)]
+/// struct Custom;
+///
+/// impl unused::inner::UnusedInner for Custom {
+///     const ARITY: usize = 0;
+///
+///     fn inconstruable(self) -> End {
+///         loop {}
+///     }
+///
+///     fn debug_variances(_tuple: &mut core::fmt::DebugTuple<'_, '_>) {}
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(Debug)]
+/// struct Custom;
+///
+/// impl unused::UnusedMarker for Custom {
+///     fn arity(&self) -> usize {
+///         0
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(Clone, Copy)]
+/// struct Custom;
+///
+/// impl unused::UnusedList for Custom {
+///     type Head = unused::End;
+///     type Tail = unused::End;
+/// }
+/// ```
+pub fn _invalid_unused_inner() {}
+
+/// Malformed invocations of the other macros are rejected.
+///
+/// ```compile_fail
+/// let _: unused::UnusedList![u8: covariant] = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::UnusedList![] = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::UnusedConst!(1.0: f32) = unused::Unused;
+/// ```
+///
+/// ```compile_fail
+/// let _: unused::UnusedMixed!(const) = unused::Unused;
+/// ```
+pub fn _malformed_other_macros() {}
+
+#[cfg(feature = "frunk")]
+#[test]
+fn frunk() {
+    use frunk::HList;
+
+    use crate::{ContravariantHList, CovariantHList, UnusedHList};
+
+    type List = HList![Rc<str>, UnsafeCell<u8>, u32];
+
+    let invariant: UnusedHList<List> = Unused;
+    let invariant: Unused!(Rc<str>, UnsafeCell<u8>, u32) = invariant;
+    let covariant: CovariantHList<List> = Unused;
+    let covariant: Unused!(Rc<str>: covariant, UnsafeCell<u8>: covariant, u32: covariant) =
+        covariant;
+    let contravariant: ContravariantHList<List> = Unused;
+    assert_eq!(
+        format!("{:#?}", contravariant),
+        "Unused(contravariant, contravariant, contravariant)"
+    );
+    auto_traits_are_implemented_by(invariant);
+    auto_traits_are_implemented_by(covariant);
+    auto_traits_are_implemented_by(contravariant);
+
+    let empty: UnusedHList<HList![]> = Unused;
+    let empty: crate::UnusedImpl<crate::End> = empty;
+    assert_eq!(empty, Unused);
+
+    fn _covariant<'a>(
+        unused: CovariantHList<HList![&'static str]>,
+    ) -> CovariantHList<HList![&'a str]> {
+        unused
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn defmt() {
+    fn is_format<T: defmt::Format>() {}
+
+    is_format::<Unused!(u8)>();
+    is_format::<Unused!(Rc<str>: covariant, u16)>();
+}
+
+#[cfg(feature = "embedded-hal")]
+#[test]
+fn embedded_hal() {
+    use embedded_hal::digital::{InputPin, OutputPin};
+
+    enum PinTag {}
+
+    /// Blinks an LED until the button is pressed, or until `cycles` blinks.
+    fn blink<L: OutputPin, B: InputPin>(
+        led: &mut L,
+        button: &mut B,
+        cycles: usize,
+    ) -> Result<usize, L::Error> {
+        let mut blinks = 0;
+        while blinks < cycles && !button.is_high().unwrap_or(true) {
+            led.set_high()?;
+            led.set_low()?;
+            blinks += 1;
+        }
+        Ok(blinks)
+    }
+
+    let mut led: Unused!(PinTag) = Unused;
+    let mut button: Unused!(PinTag) = Unused;
+    assert_eq!(blink(&mut led, &mut button, 5), Ok(5));
+    assert_eq!(button.is_low(), Ok(true));
+    led.set_state(true.into()).unwrap();
+}
+
+#[cfg(feature = "typenum")]
+#[test]
+fn typenum() {
+    use typenum::{U0, U1024, U7};
+
+    use crate::UnusedTypenumN;
+
+    fn send_sync<T: Send + Sync>(_: T) {}
+
+    let zero: Unused!(U0: covariant) = crate::typenum_n::<0>();
+    let seven: UnusedTypenumN<7> = Unused;
+    let seven: Unused!(U7: covariant) = seven;
+    let large: Unused!(U1024: covariant) = crate::typenum_n::<1024>();
+    send_sync(seven);
+    assert_eq!(
+        (
+            zero.typenum_value(),
+            seven.typenum_value(),
+            large.typenum_value()
+        ),
+        (0, 7, 1024)
+    );
+    assert_eq!(core::mem::size_of::<UnusedTypenumN<7>>(), 0);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn rayon() {
+    use rayon::iter::{
+        IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+        IntoParallelRefMutIterator, ParallelIterator,
+    };
+
+    let mut unused = <Unused!(u64)>::default();
+    assert_eq!(unused.into_par_iter().count(), 0);
+    assert_eq!(unused.par_iter().count(), 0);
+    assert_eq!(unused.par_iter_mut().count(), 0);
+    assert_eq!(unused.into_par_iter().len(), 0);
+    assert_eq!(unused.into_par_iter().opt_len(), Some(0));
+
+    let sum: u64 = unused
+        .into_par_iter()
+        .map(|never| -> u64 { match never {} })
+        .sum();
+    assert_eq!(sum, 0);
+    unused.par_iter().for_each(|never| match never {});
+    let zipped: Vec<_> = unused.into_par_iter().zip(unused).collect();
+    assert!(zipped.is_empty());
+
+    // The comparison methods are not shadowed by those of the parallel
+    // iterator traits.
+    assert!(unused.eq(&Unused));
+    assert_eq!(unused.cmp(&Unused), core::cmp::Ordering::Equal);
+    assert_eq!(unused.max(Unused), Unused);
+}
+
+#[cfg(feature = "wasm-bindgen")]
+#[test]
+fn wasm_bindgen() {
+    use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi};
+
+    fn is_wasm_abi<T: IntoWasmAbi<Abi = ()> + FromWasmAbi<Abi = ()>>() {}
+
+    is_wasm_abi::<Unused!(u8)>();
+    is_wasm_abi::<Unused!(Rc<str>: covariant, u16)>();
+
+    let unused: Unused!(u8) = Unused;
+    unused.into_abi();
+    let _unused: Unused!(u8) = unsafe { FromWasmAbi::from_abi(()) };
+}
@@ -0,0 +1,33 @@
+use core::ops::{ControlFlow, FromResidual, Residual, Try};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// Using `?` on an `Unused` always continues with the `Unused`.
+impl<T: UnusedInner> Try for UnusedImpl<T> {
+    type Output = Self;
+    type Residual = Self;
+
+    #[inline]
+    fn from_output(output: Self) -> Self {
+        output
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self, Self> {
+        ControlFlow::Continue(self)
+    }
+}
+
+/// Any residual, such as an `Err` or `None`, becomes an `Unused`, which allows
+/// `?` to be used on any type inside of a function that returns an `Unused`.
+impl<T: UnusedInner, R> FromResidual<R> for UnusedImpl<T> {
+    #[inline]
+    fn from_residual(_residual: R) -> Self {
+        Self::Unused
+    }
+}
+
+impl<T: UnusedInner> Residual<UnusedImpl<T>> for UnusedImpl<T> {
+    type TryType = Self;
+}
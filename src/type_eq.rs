@@ -0,0 +1,113 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem::ManuallyDrop;
+use core::ptr;
+
+use crate::end::End;
+use crate::invariant::Invariant;
+use crate::unused::UnusedImpl;
+
+/// A zero-sized proof that the types `A` and `B` are the same.
+///
+/// A `TypeEq` can only be created by [`TypeEq::refl`], which requires `A` and
+/// `B` to be the same type, so having one allows converting between them.
+/// This can be used to write functions that are generic, but specialize
+/// their behavior based on a proof carried by their arguments.
+///
+/// ```
+/// use unused::TypeEq;
+///
+/// enum Value<T> {
+///     Int(TypeEq<T, i64>, i64),
+///     Bool(TypeEq<T, bool>, bool),
+/// }
+///
+/// fn get<T>(value: Value<T>) -> T {
+///     match value {
+///         Value::Int(eq, int) => eq.sym().coerce(int),
+///         Value::Bool(eq, bool) => eq.sym().coerce(bool),
+///     }
+/// }
+///
+/// assert_eq!(get(Value::Int(TypeEq::refl(), 1)), 1);
+/// assert!(get(Value::Bool(TypeEq::refl(), true)));
+/// ```
+///
+/// `TypeEq` is invariant over both `A` and `B`, as otherwise a proof that
+/// `&'static str` equals itself could be turned into a proof that a
+/// shorter-lived `&'a str` equals `&'static str`.
+///
+/// A proof for different types cannot be created:
+///
+/// ```compile_fail
+/// use unused::TypeEq;
+///
+/// let eq: TypeEq<u8, u16> = TypeEq::refl();
+/// ```
+pub struct TypeEq<A: ?Sized, B: ?Sized> {
+    _unused: UnusedImpl<Invariant<A, Invariant<B, End>>>,
+}
+
+impl<T: ?Sized> TypeEq<T, T> {
+    /// Creates a proof that `T` is the same type as itself.
+    pub const fn refl() -> Self {
+        Self {
+            _unused: UnusedImpl::Unused,
+        }
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> TypeEq<A, B> {
+    /// Flips this proof, showing that `B` is the same type as `A`.
+    pub const fn sym(self) -> TypeEq<B, A> {
+        TypeEq {
+            _unused: UnusedImpl::Unused,
+        }
+    }
+
+    /// Combines this proof with a proof that `B` is the same type as `C`,
+    /// showing that `A` is the same type as `C`.
+    pub const fn trans<C: ?Sized>(self, _other: TypeEq<B, C>) -> TypeEq<A, C> {
+        TypeEq {
+            _unused: UnusedImpl::Unused,
+        }
+    }
+
+    /// Converts an `A` into a `B`, which is the same type.
+    pub fn coerce(self, a: A) -> B
+    where
+        A: Sized,
+        B: Sized,
+    {
+        let a = ManuallyDrop::new(a);
+        // SAFETY: a `TypeEq<A, B>` can only exist if `A` and `B` are the same
+        // type, and `a` is not dropped, so ownership moves into the result.
+        unsafe { ptr::read(&*a as *const A as *const B) }
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> Clone for TypeEq<A, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> Copy for TypeEq<A, B> {}
+
+impl<A: ?Sized, B: ?Sized> fmt::Debug for TypeEq<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypeEq").finish()
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> PartialEq for TypeEq<A, B> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> Eq for TypeEq<A, B> {}
+
+impl<A: ?Sized, B: ?Sized> Hash for TypeEq<A, B> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
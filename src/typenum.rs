@@ -0,0 +1,40 @@
+use typenum::{Const, ToUInt, Unsigned, U};
+
+use crate::covariant::Covariant;
+use crate::end::End;
+use crate::unused::UnusedImpl;
+use crate::Unused;
+
+/// An `Unused` for the `typenum` unsigned integer with the value `N`.
+///
+/// This bridges const generics and `typenum`: `UnusedTypenumN<5>` is the same
+/// type as `Unused!(typenum::U5: covariant)`.
+///
+/// ```
+/// use unused::{Unused, UnusedTypenumN};
+///
+/// let unused: UnusedTypenumN<5> = Unused;
+/// let _same: Unused!(typenum::U5: covariant) = unused;
+/// assert_eq!(unused.typenum_value(), 5);
+/// ```
+pub type UnusedTypenumN<const N: usize> = Unused!(U<N>: covariant);
+
+impl<N: Unsigned> UnusedImpl<Covariant<N, End>> {
+    /// Returns the value of the `typenum` unsigned integer `N`.
+    pub const fn typenum_value(self) -> usize {
+        N::USIZE
+    }
+}
+
+/// Returns an `Unused` for the `typenum` unsigned integer with the value `N`.
+///
+/// ```
+/// let unused = unused::typenum_n::<3>();
+/// assert_eq!(unused.typenum_value(), 3);
+/// ```
+pub const fn typenum_n<const N: usize>() -> UnusedTypenumN<N>
+where
+    Const<N>: ToUInt,
+{
+    UnusedImpl::Unused
+}
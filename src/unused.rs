@@ -1,37 +1,176 @@
 use core::cmp::Ordering;
+use core::convert::Infallible;
 use core::fmt;
 use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+use core::task::Waker;
 
 use crate::inner::UnusedInner;
+use crate::variance::VarianceInfo;
 
 /// A container for unused generic types
 ///
 /// This type is exported as [`Unused`](type@crate::Unused). The `Unused`
 /// variant is also exported under the same name.
+///
+/// `Unused` is `#[non_exhaustive]`, so matching on it outside of this crate
+/// requires a wildcard arm:
+///
+/// ```
+/// use unused::Unused;
+///
+/// let unused: Unused!(u8) = Unused;
+/// match unused {
+///     Unused => {}
+///     _ => unreachable!(),
+/// }
+/// ```
+#[non_exhaustive]
 pub enum UnusedImpl<T: UnusedInner> {
     Unused,
     __Inconstruable(T),
 }
 
+impl<T: UnusedInner> UnusedImpl<T> {
+    /// The number of types in this `Unused`.
+    ///
+    /// ```
+    /// use unused::Unused;
+    ///
+    /// const ARITY: usize = <Unused!(u8, u16: covariant, u32)>::ARITY;
+    /// assert_eq!(ARITY, 3);
+    /// ```
+    pub const ARITY: usize = T::ARITY;
+
+    /// Creates a new `Unused`.
+    ///
+    /// This is the same as using `Unused` as a value, or
+    /// [`Default::default`], but can be used in `const` contexts:
+    ///
+    /// ```
+    /// use unused::Unused;
+    ///
+    /// const UNUSED: Unused!(u8) = <Unused!(u8)>::new();
+    /// ```
+    #[must_use = "creating and discarding an `Unused` does nothing"]
+    pub const fn new() -> Self {
+        Self::Unused
+    }
+
+    /// Converts this `Unused` into a [`PhantomData`].
+    ///
+    /// The returned `PhantomData` has the same variances as this `Unused`, and
+    /// is also always [`Send`], [`Sync`], and [`Unpin`].
+    pub const fn phantom(self) -> PhantomData<T> {
+        PhantomData
+    }
+
+    /// Returns the variance of each type in this `Unused`, in order.
+    ///
+    /// ```
+    /// use unused::{Unused, Variance};
+    ///
+    /// let unused: Unused!(u8, u16: covariant, u32: contravariant) = Unused;
+    /// assert_eq!(
+    ///     unused.variance_info(),
+    ///     [Variance::Invariant, Variance::Covariant, Variance::Contravariant],
+    /// );
+    /// ```
+    pub const fn variance_info(self) -> VarianceInfo<T> {
+        VarianceInfo::new()
+    }
+
+    /// Returns [`ControlFlow::Continue`], as there is never a reason to break.
+    ///
+    /// This allows using `Unused` with `?` in functions that return a
+    /// `ControlFlow` that never breaks:
+    ///
+    /// ```
+    /// use core::convert::Infallible;
+    /// use core::ops::ControlFlow;
+    ///
+    /// use unused::Unused;
+    ///
+    /// fn visit(unused: Unused!(u8)) -> ControlFlow<Infallible, u8> {
+    ///     unused.to_continue()?;
+    ///     ControlFlow::Continue(1)
+    /// }
+    ///
+    /// assert_eq!(visit(Unused), ControlFlow::Continue(1));
+    /// ```
+    pub const fn to_continue(self) -> ControlFlow<Infallible, ()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Creates a [`Waker`] that does nothing when woken.
+    ///
+    /// This does not require `std` or `alloc`. With the `std` feature, a waker
+    /// can also be created from an <code>Arc<Unused<T>></code>, as `Unused`
+    /// implements [`Wake`](std::task::Wake).
+    ///
+    /// ```
+    /// use core::future::Future;
+    /// use core::pin::Pin;
+    /// use core::task::{Context, Poll};
+    ///
+    /// use unused::Unused;
+    ///
+    /// let waker = <Unused!(u8)>::new().waker();
+    /// let mut cx = Context::from_waker(&waker);
+    ///
+    /// let mut ready = core::future::ready(1);
+    /// assert_eq!(Pin::new(&mut ready).poll(&mut cx), Poll::Ready(1));
+    /// ```
+    pub fn waker(self) -> Waker {
+        crate::task::noop_waker()
+    }
+}
+
+impl<T: UnusedInner> From<PhantomData<T>> for UnusedImpl<T> {
+    fn from(_: PhantomData<T>) -> Self {
+        Self::Unused
+    }
+}
+
+impl<T: UnusedInner> From<UnusedImpl<T>> for PhantomData<T> {
+    fn from(unused: UnusedImpl<T>) -> Self {
+        unused.phantom()
+    }
+}
+
+/// `Unused` is formatted as `Unused`.
+///
+/// With the alternate flag, `{:#?}`, it is formatted along with the variance of
+/// each of its types, such as `Unused(covariant, invariant)`.
 impl<T: UnusedInner> fmt::Debug for UnusedImpl<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Unused").finish()
+        f.write_str("Unused")?;
+        if f.alternate() && T::ARITY > 0 {
+            f.write_str("(")?;
+            for (i, variance) in self.variance_info().into_iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                fmt::Display::fmt(&variance, f)?;
+            }
+            f.write_str(")")?;
+        }
+        Ok(())
     }
 }
 
 impl<T: UnusedInner> Clone for UnusedImpl<T> {
     fn clone(&self) -> Self {
-        Self::Unused
+        *self
     }
-
-    fn clone_from(&mut self, _source: &Self) {}
 }
 
 impl<T: UnusedInner> Copy for UnusedImpl<T> {}
 
 impl<T: UnusedInner> Default for UnusedImpl<T> {
     fn default() -> Self {
-        Self::Unused
+        Self::new()
     }
 }
 
@@ -44,8 +183,8 @@ impl<T: UnusedInner> PartialEq for UnusedImpl<T> {
 impl<T: UnusedInner> Eq for UnusedImpl<T> {}
 
 impl<T: UnusedInner> PartialOrd for UnusedImpl<T> {
-    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
-        Some(Ordering::Equal)
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
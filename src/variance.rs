@@ -0,0 +1,161 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// The variance of a type in an `Unused`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Variance {
+    /// The type is invariant.
+    Invariant,
+    /// The type is covariant.
+    Covariant,
+    /// The type is contravariant.
+    Contravariant,
+}
+
+/// A `Variance` is displayed in the same way as it is written in the
+/// `Unused!` macro, such as `covariant`.
+impl fmt::Display for Variance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Invariant => "invariant",
+            Self::Covariant => "covariant",
+            Self::Contravariant => "contravariant",
+        })
+    }
+}
+
+/// The variances of the types in an `Unused<T>`, in order.
+///
+/// This is returned by `Unused::variance_info`, and can be compared with a
+/// slice or an array of [`Variance`]s.
+pub struct VarianceInfo<T: UnusedInner> {
+    _unused: UnusedImpl<T>,
+}
+
+impl<T: UnusedInner> VarianceInfo<T> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            _unused: UnusedImpl::Unused,
+        }
+    }
+
+    /// Returns the number of types.
+    pub const fn len(&self) -> usize {
+        T::ARITY
+    }
+
+    /// Returns whether there are no types.
+    pub const fn is_empty(&self) -> bool {
+        T::ARITY == 0
+    }
+
+    /// Returns the variance of the type at `index`, or `None` if `index` is
+    /// out of bounds.
+    pub fn get(&self, index: usize) -> Option<Variance> {
+        T::variance(index)
+    }
+
+    /// Returns an iterator over the variances.
+    pub fn iter(&self) -> Variances<T> {
+        Variances {
+            _unused: UnusedImpl::Unused,
+            range: 0..T::ARITY,
+        }
+    }
+}
+
+impl<T: UnusedInner> Clone for VarianceInfo<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: UnusedInner> Copy for VarianceInfo<T> {}
+
+impl<T: UnusedInner> fmt::Debug for VarianceInfo<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: UnusedInner> PartialEq for VarianceInfo<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T: UnusedInner> Eq for VarianceInfo<T> {}
+
+impl<T: UnusedInner> PartialEq<[Variance]> for VarianceInfo<T> {
+    fn eq(&self, other: &[Variance]) -> bool {
+        self.iter().eq(other.iter().copied())
+    }
+}
+
+impl<'a, T: UnusedInner> PartialEq<&'a [Variance]> for VarianceInfo<T> {
+    fn eq(&self, other: &&'a [Variance]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: UnusedInner, const N: usize> PartialEq<[Variance; N]> for VarianceInfo<T> {
+    fn eq(&self, other: &[Variance; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: UnusedInner> IntoIterator for VarianceInfo<T> {
+    type Item = Variance;
+    type IntoIter = Variances<T>;
+
+    fn into_iter(self) -> Variances<T> {
+        self.iter()
+    }
+}
+
+/// An iterator over the variances in a [`VarianceInfo`].
+pub struct Variances<T: UnusedInner> {
+    _unused: UnusedImpl<T>,
+    range: Range<usize>,
+}
+
+impl<T: UnusedInner> Clone for Variances<T> {
+    fn clone(&self) -> Self {
+        Self {
+            _unused: UnusedImpl::Unused,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<T: UnusedInner> fmt::Debug for Variances<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<T: UnusedInner> Iterator for Variances<T> {
+    type Item = Variance;
+
+    fn next(&mut self) -> Option<Variance> {
+        self.range.next().and_then(T::variance)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<T: UnusedInner> DoubleEndedIterator for Variances<T> {
+    fn next_back(&mut self) -> Option<Variance> {
+        self.range.next_back().and_then(T::variance)
+    }
+}
+
+impl<T: UnusedInner> ExactSizeIterator for Variances<T> {}
+
+impl<T: UnusedInner> FusedIterator for Variances<T> {}
@@ -0,0 +1,99 @@
+/// Asserts that a type that contains an [`Unused`](type@crate::Unused) has
+/// the expected variance over one of its type parameters.
+///
+/// The other type parameters are replaced with `()`.
+///
+/// Covariance and contravariance are checked at compile time, by coercing
+/// between lifetimes:
+///
+/// ```
+/// use unused::{verify_unused, Unused};
+///
+/// struct Reader<T, U> {
+///     unused: Unused!(T: covariant, U: contravariant),
+/// }
+///
+/// verify_unused!(Reader<T, U> is_covariant_over T);
+/// verify_unused!(Reader<T, U> is_contravariant_over U);
+/// ```
+///
+/// If the variance is wrong, it does not compile:
+///
+/// ```compile_fail
+/// use unused::{verify_unused, Unused};
+///
+/// struct Reader<T> {
+///     unused: Unused!(T),
+/// }
+///
+/// verify_unused!(Reader<T> is_covariant_over T);
+/// ```
+///
+/// There is no `is_invariant_over`, as a type cannot be shown to be invariant
+/// by code that compiles. Instead, check that both of the other variances do
+/// not compile, for example in `compile_fail` doctests:
+///
+/// ```compile_fail
+/// use unused::{verify_unused, Unused};
+///
+/// struct Cell<T> {
+///     unused: Unused!(T: invariant),
+/// }
+///
+/// verify_unused!(Cell<T> is_covariant_over T);
+/// ```
+///
+/// ```compile_fail
+/// use unused::{verify_unused, Unused};
+///
+/// struct Cell<T> {
+///     unused: Unused!(T: invariant),
+/// }
+///
+/// verify_unused!(Cell<T> is_contravariant_over T);
+/// ```
+#[macro_export]
+macro_rules! verify_unused {
+    ($name:ident<$($param:ident),+ $(,)?> is_covariant_over $target:ident) => {
+        $crate::__impl_verify_unused!(@[$name] [$($param),+] [$target] covariant);
+    };
+    ($name:ident<$($param:ident),+ $(,)?> is_contravariant_over $target:ident) => {
+        $crate::__impl_verify_unused!(@[$name] [$($param),+] [$target] contravariant);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_verify_unused {
+    (@[$name:ident] [$($param:ident),+] [$target:ident] $variance:ident) => {
+        const _: () = {
+            // Replaces the target with a reference, and every other parameter
+            // with `()`. Every arm matches literal identifiers, as this macro
+            // is generated by another macro.
+            macro_rules! __subst {
+                ($target long) => { &'long () };
+                ($target short) => { &'short () };
+                $(
+                    ($param long) => { () };
+                    ($param short) => { () };
+                )+
+            }
+
+            $crate::__impl_verify_unused!(@$variance [$name] [$($param),+]);
+        };
+    };
+    (@covariant [$name:ident] [$($param:ident),+]) => {
+        $crate::__impl_verify_unused!(@check [$name] [$($param),+] long short);
+    };
+    (@contravariant [$name:ident] [$($param:ident),+]) => {
+        $crate::__impl_verify_unused!(@check [$name] [$($param),+] short long);
+    };
+    (@check [$name:ident] [$($param:ident),+] $from:ident $to:ident) => {
+        #[allow(dead_code)]
+        fn verify<'short, 'long: 'short>(
+            value: $name<$(__subst!($param $from)),+>,
+        ) -> $name<$(__subst!($param $to)),+> {
+            value
+        }
+    };
+}
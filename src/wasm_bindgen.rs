@@ -0,0 +1,29 @@
+use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi};
+use wasm_bindgen::describe::WasmDescribe;
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// `Unused` is passed to and from JavaScript in the same way as `()`, which is
+/// `undefined`.
+impl<T: UnusedInner> WasmDescribe for UnusedImpl<T> {
+    fn describe() {
+        <()>::describe();
+    }
+}
+
+impl<T: UnusedInner> IntoWasmAbi for UnusedImpl<T> {
+    type Abi = ();
+
+    #[inline]
+    fn into_abi(self) {}
+}
+
+impl<T: UnusedInner> FromWasmAbi for UnusedImpl<T> {
+    type Abi = ();
+
+    #[inline]
+    unsafe fn from_abi(_js: ()) -> Self {
+        Self::Unused
+    }
+}
@@ -0,0 +1,124 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::inner::UnusedInner;
+use crate::unused::UnusedImpl;
+
+/// An [`Unused`](type@crate::Unused) carried together with a value, usually a
+/// zero-sized marker such as `()` or a unit struct.
+///
+/// `UnusedWith` has the same size as `U`, so it is zero-sized whenever `U`
+/// is:
+///
+/// ```
+/// use unused::{Unused, UnusedWith};
+///
+/// #[derive(Debug, Clone, Copy, Default, PartialEq)]
+/// struct AllocTag;
+///
+/// let unused: Unused!(String) = Unused;
+/// let with = UnusedWith::from((unused, AllocTag));
+/// assert_eq!(*with.value(), AllocTag);
+/// assert_eq!(core::mem::size_of_val(&with), 0);
+/// ```
+///
+/// The `Unused` does not affect the auto traits of `UnusedWith`, so it is
+/// [`Send`] and [`Sync`] if and only if `U` is.
+///
+/// The other traits of `UnusedWith`, such as [`Clone`] and [`PartialEq`], are
+/// implemented if `U` implements them, and only use the value.
+pub struct UnusedWith<T: UnusedInner, U> {
+    unused: UnusedImpl<T>,
+    value: U,
+}
+
+impl<T: UnusedInner, U> UnusedWith<T, U> {
+    /// Creates a new `UnusedWith` with a value.
+    pub const fn new(value: U) -> Self {
+        Self {
+            unused: UnusedImpl::Unused,
+            value,
+        }
+    }
+
+    /// Returns the `Unused`.
+    pub const fn unused(&self) -> UnusedImpl<T> {
+        UnusedImpl::Unused
+    }
+
+    /// Returns a reference to the value.
+    pub const fn value(&self) -> &U {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the value.
+    pub fn value_mut(&mut self) -> &mut U {
+        &mut self.value
+    }
+
+    /// Returns the value, discarding the `Unused`.
+    pub fn into_value(self) -> U {
+        self.value
+    }
+
+    /// Splits this `UnusedWith` into its `Unused` and its value.
+    pub fn into_parts(self) -> (UnusedImpl<T>, U) {
+        (self.unused, self.value)
+    }
+}
+
+impl<T: UnusedInner, U> From<(UnusedImpl<T>, U)> for UnusedWith<T, U> {
+    fn from((_unused, value): (UnusedImpl<T>, U)) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: UnusedInner, U: Clone> Clone for UnusedWith<T, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: UnusedInner, U: Copy> Copy for UnusedWith<T, U> {}
+
+impl<T: UnusedInner, U: fmt::Debug> fmt::Debug for UnusedWith<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnusedWith")
+            .field("unused", &self.unused)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<T: UnusedInner, U: Default> Default for UnusedWith<T, U> {
+    fn default() -> Self {
+        Self::new(U::default())
+    }
+}
+
+impl<T: UnusedInner, U: PartialEq> PartialEq for UnusedWith<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: UnusedInner, U: Eq> Eq for UnusedWith<T, U> {}
+
+impl<T: UnusedInner, U: PartialOrd> PartialOrd for UnusedWith<T, U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: UnusedInner, U: Ord> Ord for UnusedWith<T, U> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: UnusedInner, U: Hash> Hash for UnusedWith<T, U> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
@@ -0,0 +1,121 @@
+//! Round-trips `Unused` through `bincode` and `postcard`, which both encode
+//! it as zero bytes.
+//!
+//! Both codecs are commonly used without `std`, so these tests only use
+//! `core` and `alloc`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use bincode::{config, Decode, Encode};
+use postcard::experimental::max_size::MaxSize;
+use serde::{Deserialize, Serialize};
+use unused::Unused;
+
+type UnusedU8 = Unused!(u8);
+
+#[derive(Encode, Decode, Serialize, Deserialize, Debug, PartialEq)]
+struct Packet {
+    id: u8,
+    unused: UnusedU8,
+    value: u16,
+}
+
+const PACKET: Packet = Packet {
+    id: 1,
+    unused: Unused,
+    value: 300,
+};
+
+#[test]
+fn bincode_round_trip() {
+    let config = config::standard();
+    let unused: Unused!(u8) = Unused;
+    let bytes: Vec<u8> = bincode::encode_to_vec(unused, config).unwrap();
+    assert!(bytes.is_empty());
+    assert_eq!(
+        bincode::decode_from_slice::<Unused!(u8), _>(&bytes, config).unwrap(),
+        (Unused, 0)
+    );
+
+    let bytes = bincode::encode_to_vec(&PACKET, config).unwrap();
+    assert_eq!(
+        bytes,
+        bincode::encode_to_vec((1u8, 300u16), config).unwrap()
+    );
+    assert_eq!(
+        bincode::decode_from_slice(&bytes, config).unwrap(),
+        (PACKET, bytes.len())
+    );
+}
+
+#[test]
+fn bincode_without_alloc() {
+    let config = config::standard();
+    let mut buf = [0xff; 4];
+    let unused: Unused!(u8) = Unused;
+    assert_eq!(
+        bincode::encode_into_slice(unused, &mut buf, config).unwrap(),
+        0
+    );
+    assert_eq!(buf, [0xff; 4]);
+}
+
+#[test]
+fn bincode_leaves_other_bytes_unread() {
+    let config = config::standard();
+    let (unused, read): (Unused!(u8), _) = bincode::decode_from_slice(&[1, 2], config).unwrap();
+    assert_eq!((unused, read), (Unused, 0));
+    let (unused, read): (Unused!(u8), _) =
+        bincode::borrow_decode_from_slice(&[1, 2], config).unwrap();
+    assert_eq!((unused, read), (Unused, 0));
+
+    // A missing field after an `Unused` is an error, not a panic.
+    assert!(bincode::decode_from_slice::<Packet, _>(&[1], config).is_err());
+}
+
+#[test]
+fn postcard_round_trip() {
+    let unused: Unused!(u8) = Unused;
+    let bytes = postcard::to_allocvec(&unused).unwrap();
+    assert!(bytes.is_empty());
+    assert_eq!(postcard::from_bytes::<Unused!(u8)>(&bytes).unwrap(), Unused);
+
+    let bytes = postcard::to_allocvec(&PACKET).unwrap();
+    assert_eq!(bytes, postcard::to_allocvec(&(1u8, 300u16)).unwrap());
+    assert_eq!(postcard::from_bytes::<Packet>(&bytes).unwrap(), PACKET);
+}
+
+#[test]
+fn postcard_without_alloc() {
+    let mut buf = [0xff; 4];
+    let unused: Unused!(u8) = Unused;
+    assert!(postcard::to_slice(&unused, &mut buf).unwrap().is_empty());
+    assert_eq!(buf, [0xff; 4]);
+}
+
+#[test]
+fn postcard_leaves_other_bytes_unread() {
+    let (unused, rest) = postcard::take_from_bytes::<Unused!(u8)>(&[1, 2]).unwrap();
+    assert_eq!((unused, rest), (Unused, &[1, 2][..]));
+    assert_eq!(
+        postcard::from_bytes::<(Unused!(u8), u8)>(&[7]).unwrap(),
+        (Unused, 7)
+    );
+
+    // A missing field after an `Unused` is an error, not a panic.
+    assert!(postcard::from_bytes::<Packet>(&[1]).is_err());
+}
+
+#[test]
+fn postcard_max_size() {
+    assert_eq!(<Unused!(u8)>::POSTCARD_MAX_SIZE, 0);
+    assert_eq!(<Unused!(u8, u16: covariant)>::POSTCARD_MAX_SIZE, 0);
+    assert_eq!(
+        <(u8, Unused!(u8), u16)>::POSTCARD_MAX_SIZE,
+        u8::POSTCARD_MAX_SIZE + u16::POSTCARD_MAX_SIZE
+    );
+}
@@ -0,0 +1,80 @@
+//! Compares the auto traits of `PhantomData<X>` with those of `Unused!(X)`.
+//!
+//! `PhantomData<X>` is `Send` and `Sync` only if `X` is, while `Unused!(X)`
+//! always is, whatever its variance. Each row of the matrix is checked at
+//! compile time.
+
+use std::any::Any;
+use std::cell::{Cell, OnceCell, RefCell, UnsafeCell};
+use std::marker::{PhantomData, PhantomPinned};
+use std::ptr::NonNull;
+use std::rc::{self, Rc};
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use unused::Unused;
+
+macro_rules! check_phantom {
+    ($trait:ident: yes, $ty:ty) => {
+        assert_impl_all!(PhantomData<$ty>: $trait);
+    };
+    ($trait:ident: no, $ty:ty) => {
+        assert_not_impl_any!(PhantomData<$ty>: $trait);
+    };
+}
+
+macro_rules! matrix {
+    ($($ty:ty => Send: $send:ident, Sync: $sync:ident;)*) => {
+        $(
+            check_phantom!(Send: $send, $ty);
+            check_phantom!(Sync: $sync, $ty);
+            assert_impl_all!(Unused!($ty): Send, Sync);
+            assert_impl_all!(Unused!($ty: covariant): Send, Sync);
+            assert_impl_all!(Unused!($ty: contravariant): Send, Sync);
+        )*
+    };
+}
+
+matrix! {
+    u8 => Send: yes, Sync: yes;
+    String => Send: yes, Sync: yes;
+    Box<u8> => Send: yes, Sync: yes;
+    Arc<u8> => Send: yes, Sync: yes;
+    Mutex<u8> => Send: yes, Sync: yes;
+    RwLock<u8> => Send: yes, Sync: yes;
+    PhantomPinned => Send: yes, Sync: yes;
+    Cell<u8> => Send: yes, Sync: no;
+    RefCell<u8> => Send: yes, Sync: no;
+    UnsafeCell<u8> => Send: yes, Sync: no;
+    OnceCell<u8> => Send: yes, Sync: no;
+    Receiver<u8> => Send: yes, Sync: no;
+    &'static mut Cell<u8> => Send: yes, Sync: no;
+    MutexGuard<'static, u8> => Send: no, Sync: yes;
+    Rc<u8> => Send: no, Sync: no;
+    rc::Weak<u8> => Send: no, Sync: no;
+    Arc<Cell<u8>> => Send: no, Sync: no;
+    Mutex<Rc<u8>> => Send: no, Sync: no;
+    Cell<Rc<u8>> => Send: no, Sync: no;
+    &'static Cell<u8> => Send: no, Sync: no;
+    *mut u8 => Send: no, Sync: no;
+    *const u8 => Send: no, Sync: no;
+    NonNull<u8> => Send: no, Sync: no;
+    dyn Any => Send: no, Sync: no;
+    dyn Any + Send => Send: yes, Sync: no;
+    dyn Any + Send + Sync => Send: yes, Sync: yes;
+}
+
+/// The `PhantomData` returned by `into_phantom` is over the types of the
+/// `Unused`, so it keeps the auto traits of the `Unused`.
+#[test]
+fn into_phantom_keeps_auto_traits() {
+    fn send_sync<T: Send + Sync>(_: &T) {}
+
+    let phantom = unused::into_phantom(<Unused!(Rc<u8>, *mut u8: covariant)>::new());
+    send_sync(&phantom);
+
+    let unused: Unused!(Rc<u8>, *mut u8: covariant) = unused::from_phantom(phantom);
+    send_sync(&unused);
+    assert_eq!(unused, Unused);
+}
@@ -0,0 +1,75 @@
+//! Checks how `Unused` is formatted, both with `format!` and by writing into a
+//! fixed buffer, as a `no_std` crate would.
+
+#![no_std]
+
+extern crate std;
+
+use core::fmt::{self, Write};
+
+use unused::Unused;
+
+type TestUnused = Unused!(u8, i32: covariant);
+
+/// A fixed-size buffer that can be written to without allocating.
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Formats `args` into a new `Buffer`.
+fn buffer(args: fmt::Arguments<'_>) -> Buffer {
+    let mut buffer = Buffer::new();
+    buffer.write_fmt(args).unwrap();
+    buffer
+}
+
+#[test]
+fn display() {
+    assert_eq!(std::format!("{}", TestUnused::default()), "Unused");
+    assert_eq!(
+        buffer(format_args!("{}", TestUnused::default())).as_str(),
+        "Unused"
+    );
+    assert_eq!(
+        buffer(format_args!("[{:>8}]", TestUnused::default())).as_str(),
+        "[  Unused]"
+    );
+}
+
+#[test]
+fn numeric() {
+    let unused = TestUnused::default();
+
+    assert_eq!(buffer(format_args!("{:x}", unused)).as_str(), "0");
+    assert_eq!(buffer(format_args!("{:X}", unused)).as_str(), "0");
+    assert_eq!(buffer(format_args!("{:o}", unused)).as_str(), "0");
+    assert_eq!(buffer(format_args!("{:b}", unused)).as_str(), "0");
+    assert_eq!(buffer(format_args!("{:#x}", unused)).as_str(), "0x0");
+    assert_eq!(buffer(format_args!("{:04b}", unused)).as_str(), "0000");
+}
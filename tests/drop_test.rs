@@ -0,0 +1,47 @@
+//! Checks that `Unused` never needs to be dropped, and behaves like any other
+//! `Copy` type with the functions in `core::mem`.
+
+use std::mem::{self, ManuallyDrop};
+use std::rc::Rc;
+
+use unused::Unused;
+
+type TestUnused = Unused!(String, Rc<u8>: covariant);
+
+#[test]
+fn needs_drop() {
+    assert!(!mem::needs_drop::<Unused!(u8)>());
+    assert!(!mem::needs_drop::<TestUnused>());
+    assert!(!mem::needs_drop::<Option<TestUnused>>());
+    assert!(!mem::needs_drop::<[TestUnused; 4]>());
+}
+
+#[test]
+fn manually_drop() {
+    let mut unused = ManuallyDrop::new(<Unused!(u8)>::default());
+    assert_eq!(*unused, Unused);
+    // SAFETY: `unused` is not used after being dropped.
+    unsafe { ManuallyDrop::drop(&mut unused) };
+
+    let unused = ManuallyDrop::new(TestUnused::default());
+    assert_eq!(ManuallyDrop::into_inner(unused), Unused);
+}
+
+// Forgetting a `Copy` type is linted as doing nothing, which is what this
+// checks.
+#[allow(forgetting_copy_types)]
+#[test]
+fn forget() {
+    let unused = <Unused!(u8)>::default();
+    mem::forget(unused);
+    // `Unused` is `Copy`, so it can still be used after being forgotten.
+    assert_eq!(unused, Unused);
+}
+
+#[test]
+fn replace() {
+    let mut value: TestUnused = Unused;
+    let old = mem::replace(&mut value, Unused);
+    assert_eq!(old, value);
+    assert_eq!(mem::take(&mut value), Unused);
+}
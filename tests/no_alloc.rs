@@ -0,0 +1,79 @@
+//! Checks that writing to `Unused` with `write!` never allocates.
+
+#![no_std]
+
+extern crate std;
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::cell::Cell;
+use core::fmt::Write;
+
+use std::alloc::System;
+
+use unused::Unused;
+
+std::thread_local! {
+    /// Whether allocations are forbidden on the current thread.
+    static FORBIDDEN: Cell<bool> = const { Cell::new(false) };
+}
+
+/// An allocator which panics on any allocation made on a thread where
+/// allocations are forbidden.
+struct PanickingAlloc;
+
+unsafe impl GlobalAlloc for PanickingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if FORBIDDEN.with(Cell::get) {
+            FORBIDDEN.with(|forbidden| forbidden.set(false));
+            panic!(
+                "allocated {} bytes while writing to `Unused`",
+                layout.size()
+            );
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: PanickingAlloc = PanickingAlloc;
+
+/// Runs `f` with allocations forbidden on the current thread.
+fn forbid_alloc<R>(f: impl FnOnce() -> R) -> R {
+    FORBIDDEN.with(|forbidden| forbidden.set(true));
+    let result = f();
+    FORBIDDEN.with(|forbidden| forbidden.set(false));
+    result
+}
+
+#[test]
+#[should_panic(expected = "while writing to `Unused`")]
+fn allocator_panics() {
+    forbid_alloc(|| std::vec![0u8; 16]);
+}
+
+#[test]
+fn write() {
+    let mut unused: Unused!(u32) = Unused;
+
+    forbid_alloc(|| {
+        write!(unused, "{} {:?} {:>8} {:#x}", 42, "str", 1.5, 255).unwrap();
+        writeln!(unused).unwrap();
+        unused.write_str("str").unwrap();
+        unused.write_char('c').unwrap();
+    });
+}
+
+#[test]
+fn write_long() {
+    let mut unused: Unused!(u32: covariant, u8) = Unused;
+
+    forbid_alloc(|| {
+        for i in 0..1024 {
+            write!(unused, "{:>1$}", i, i).unwrap();
+        }
+    });
+}
@@ -0,0 +1,60 @@
+//! Checks that structs with `Unused` fields can be used in `static`s, even if
+//! the unused types are neither `Send` nor `Sync`.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use once_cell::sync::Lazy;
+use unused::{PhantomBottom, Unused, EMPTY};
+
+struct MyStruct<T> {
+    name: &'static str,
+    unused: Unused!(T),
+}
+
+impl<T> MyStruct<T> {
+    const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            unused: Unused,
+        }
+    }
+}
+
+static RC: MyStruct<Rc<String>> = MyStruct {
+    name: "rc",
+    unused: Unused,
+};
+
+static CELL: MyStruct<Cell<u8>> = MyStruct::new("cell");
+
+static BOTTOM: PhantomBottom = EMPTY;
+
+lazy_static::lazy_static! {
+    static ref LAZY_STATIC: MyStruct<Rc<String>> = MyStruct::new("lazy_static");
+}
+
+static ONCE_CELL: Lazy<MyStruct<Rc<String>>> = Lazy::new(|| MyStruct::new("once_cell"));
+
+#[test]
+fn statics() {
+    assert_eq!(RC.name, "rc");
+    assert_eq!(RC.unused, Unused);
+    assert_eq!(CELL.name, "cell");
+    assert_eq!(CELL.unused, Unused);
+    assert_eq!(BOTTOM, Unused);
+}
+
+#[test]
+fn lazy() {
+    assert_eq!(LAZY_STATIC.name, "lazy_static");
+    assert_eq!(LAZY_STATIC.unused, Unused);
+    assert_eq!(ONCE_CELL.name, "once_cell");
+    assert_eq!(ONCE_CELL.unused, Unused);
+}
+
+#[test]
+fn shared_between_threads() {
+    let name = std::thread::spawn(|| ONCE_CELL.name).join().unwrap();
+    assert_eq!(name, "once_cell");
+}
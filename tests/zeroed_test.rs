@@ -0,0 +1,63 @@
+//! Checks that a zeroed or uninitialized `Unused` is valid.
+//!
+//! `Unused` is zero-sized and only has one value, so it has no bytes that
+//! could be invalid. None of the `unsafe` blocks below need
+//! `#[allow(invalid_value)]`.
+
+use std::mem::{self, MaybeUninit};
+use std::rc::Rc;
+
+use unused::Unused;
+
+type TestUnused = Unused!(Rc<u8>, String: covariant, u8: contravariant);
+
+// SAFETY: `Unused` is zero-sized and inhabited only by `Unused`, so the
+// all-zero bit pattern, which is empty, is a valid `Unused`.
+const ZEROED: TestUnused = unsafe { MaybeUninit::zeroed().assume_init() };
+
+// SAFETY: as above.
+const MEM_ZEROED: TestUnused = unsafe { mem::zeroed() };
+
+#[test]
+fn maybe_uninit_zeroed() {
+    // SAFETY: `Unused` is zero-sized, and `Unused` is its only value.
+    let unused: Unused!(u8) = unsafe { MaybeUninit::zeroed().assume_init() };
+    assert_eq!(unused, Unused);
+
+    // SAFETY: as above.
+    let unused: TestUnused = unsafe { MaybeUninit::zeroed().assume_init() };
+    assert_eq!(unused, Unused);
+}
+
+#[test]
+fn maybe_uninit_uninit() {
+    // SAFETY: a zero-sized type has no bytes to leave uninitialized, so this
+    // is valid as well.
+    let unused: TestUnused = unsafe { MaybeUninit::uninit().assume_init() };
+    assert_eq!(unused, Unused);
+
+    let mut uninit = MaybeUninit::<TestUnused>::uninit();
+    uninit.write(Unused);
+    // SAFETY: `uninit` was initialized above.
+    assert_eq!(unsafe { uninit.assume_init() }, Unused);
+}
+
+#[test]
+fn mem_zeroed() {
+    // SAFETY: `Unused` is zero-sized, and `Unused` is its only value.
+    let unused: Unused!(u8) = unsafe { mem::zeroed() };
+    assert_eq!(unused, Unused);
+}
+
+#[test]
+fn const_zeroed() {
+    assert_eq!(ZEROED, Unused);
+    assert_eq!(MEM_ZEROED, Unused);
+
+    const fn zeroed() -> TestUnused {
+        // SAFETY: `Unused` is zero-sized, and `Unused` is its only value.
+        unsafe { MaybeUninit::zeroed().assume_init() }
+    }
+    const FROM_FN: TestUnused = zeroed();
+    assert_eq!(FROM_FN, Unused);
+}
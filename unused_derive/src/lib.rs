@@ -0,0 +1,618 @@
+//! # About Unused Derive
+//!
+//! Attribute and derive macros for the [`unused`](https://docs.rs/unused) crate.
+//!
+//! See [`macro@unused_params`], [`macro@unused_field`], and
+//! [`derive@UnusedDebug`] for more information.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::visit::Visit;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Field, Fields, GenericParam, Ident,
+    Index, ItemStruct, Lifetime, Result, Token, Type,
+};
+
+/// An attribute that adds an `Unused` field for the listed generic type
+/// parameters to a struct.
+///
+/// Parameters are listed in the same way as with the `Unused!` macro, and
+/// default to being invariant.
+///
+/// ```
+/// use unused_derive::unused_params;
+///
+/// #[unused_params(T, U: covariant)]
+/// #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+/// struct Foo<T, U> {
+///     value: u8,
+/// }
+///
+/// let foo: Foo<String, u8> = Foo {
+///     value: 1,
+///     __unused: unused::Unused,
+/// };
+/// ```
+///
+/// The added field is private and named `__unused`. For tuple structs, it is
+/// added as the last field.
+///
+/// The attribute must be placed above any derives, so that they can see the
+/// added field. The field type does not use the `Unused!` macro, as derives
+/// cannot be used on structs that have macros in field types.
+///
+/// Listing a type that is not a generic parameter of the struct is an error:
+///
+/// ```compile_fail
+/// use unused_derive::unused_params;
+///
+/// #[unused_params(T, U)]
+/// struct Foo<T> {
+///     value: u8,
+/// }
+/// ```
+///
+/// So is an unknown variance:
+///
+/// ```compile_fail
+/// use unused_derive::unused_params;
+///
+/// #[unused_params(T: sideways)]
+/// struct Foo<T> {
+///     value: u8,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn unused_params(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut item = parse_macro_input!(item as ItemStruct);
+    let result = Punctuated::<Param, Token![,]>::parse_terminated
+        .parse(attr)
+        .and_then(|params| add_unused_field(&mut item, params.into_iter().collect()));
+    match result {
+        Ok(()) => quote!(#item).into(),
+        Err(err) => {
+            let err = err.to_compile_error();
+            quote!(#item #err).into()
+        }
+    }
+}
+
+/// An attribute that adds an `Unused` field for every generic parameter that
+/// is not used by any of a struct's fields.
+///
+/// Unused type parameters are added as-is, and unused lifetimes `'a` are added
+/// as `&'a ()`. They default to being invariant, which is always sound; the
+/// variance of individual parameters can be changed with
+/// `#[unused_variance(...)]`, using the same syntax as the `Unused!` macro.
+///
+/// ```
+/// use unused_derive::unused_field;
+///
+/// #[unused_field]
+/// #[unused_variance('a: covariant, U: contravariant)]
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// struct Foo<'a, T, U> {
+///     value: T,
+/// }
+///
+/// let foo: Foo<'static, u8, String> = Foo {
+///     value: 1,
+///     __unused: unused::Unused,
+/// };
+/// ```
+///
+/// As with [`macro@unused_params`], the added field is private and named
+/// `__unused`, and the attribute must be placed above any derives. Const
+/// generic parameters are never considered unused, and an identifier that
+/// appears anywhere inside a macro in a field type counts as a use.
+///
+/// Overriding the variance of a parameter that is used by a field is an
+/// error, as its variance is already determined by that field:
+///
+/// ```compile_fail
+/// use unused_derive::unused_field;
+///
+/// #[unused_field]
+/// #[unused_variance(T: covariant)]
+/// struct Foo<T, U> {
+///     value: T,
+/// }
+/// ```
+///
+/// So is using the attribute on a struct without any unused parameters:
+///
+/// ```compile_fail
+/// use unused_derive::unused_field;
+///
+/// #[unused_field]
+/// struct Foo<T> {
+///     value: T,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn unused_field(attr: TokenStream, item: TokenStream) -> TokenStream {
+    parse_macro_input!(attr as syn::parse::Nothing);
+    let mut item = parse_macro_input!(item as ItemStruct);
+    match add_inferred_field(&mut item) {
+        Ok(()) => quote!(#item).into(),
+        Err(err) => {
+            let err = err.to_compile_error();
+            quote!(#item #err).into()
+        }
+    }
+}
+
+/// A derive for `Debug` that leaves out fields marked with
+/// `#[unused_debug_skip]`, such as `Unused` fields.
+///
+/// ```
+/// use unused::Unused;
+/// use unused_derive::UnusedDebug;
+///
+/// #[derive(UnusedDebug)]
+/// struct Foo<T> {
+///     value: u8,
+///     #[unused_debug_skip]
+///     unused: Unused!(T),
+/// }
+///
+/// let foo: Foo<String> = Foo {
+///     value: 1,
+///     unused: Unused,
+/// };
+/// assert_eq!(format!("{:?}", foo), "Foo { value: 1 }");
+/// ```
+///
+/// Like `#[derive(Debug)]`, the type parameters of the struct are required to
+/// implement `Debug`, but only if they are used by a field that is not
+/// skipped. `Unused` fields are `Debug` whatever their types are, so they do
+/// not require their types to implement `Debug` either, and `Foo<T>` above
+/// implements `Debug` for any `T`. A parameter that is used by another field
+/// still has to implement `Debug`:
+///
+/// ```compile_fail
+/// use unused_derive::UnusedDebug;
+///
+/// #[derive(UnusedDebug)]
+/// struct Foo<T> {
+///     value: T,
+/// }
+///
+/// struct NotDebug;
+///
+/// format!("{:?}", Foo { value: NotDebug });
+/// ```
+///
+/// `#[unused_debug_skip]` does not take any arguments:
+///
+/// ```compile_fail
+/// use unused::Unused;
+/// use unused_derive::UnusedDebug;
+///
+/// #[derive(UnusedDebug)]
+/// struct Foo<T> {
+///     value: u8,
+///     #[unused_debug_skip(true)]
+///     unused: Unused!(T),
+/// }
+/// ```
+///
+/// Only structs are supported:
+///
+/// ```compile_fail
+/// use unused_derive::UnusedDebug;
+///
+/// #[derive(UnusedDebug)]
+/// enum Foo {
+///     Bar,
+/// }
+/// ```
+#[proc_macro_derive(UnusedDebug, attributes(unused_debug_skip))]
+pub fn unused_debug(item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as DeriveInput);
+    debug_impl(&item)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+const FIELD_NAME: &str = "__unused";
+
+fn add_unused_field(item: &mut ItemStruct, params: Vec<Param>) -> Result<()> {
+    if params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.ident,
+            "`unused_params` requires at least one generic type parameter",
+        ));
+    }
+
+    for (i, param) in params.iter().enumerate() {
+        if !item.generics.type_params().any(|p| p.ident == param.ident) {
+            return Err(Error::new(
+                param.ident.span(),
+                format!(
+                    "`{}` is not a generic type parameter of `{}`",
+                    param.ident, item.ident,
+                ),
+            ));
+        }
+        if params[..i].iter().any(|p| p.ident == param.ident) {
+            return Err(Error::new(
+                param.ident.span(),
+                format!("`{}` is listed more than once", param.ident),
+            ));
+        }
+    }
+
+    let ty = unused_type(
+        params
+            .iter()
+            .map(|param| (param.ident.to_token_stream(), &param.variance)),
+    );
+    push_field(item, ty, "unused_params")
+}
+
+const VARIANCE_ATTR: &str = "unused_variance";
+
+fn add_inferred_field(item: &mut ItemStruct) -> Result<()> {
+    let mut overrides = Vec::new();
+    let mut result = Ok(());
+    item.attrs.retain(|attr| {
+        if !attr.path().is_ident(VARIANCE_ATTR) {
+            return true;
+        }
+        match attr.parse_args_with(Punctuated::<Override, Token![,]>::parse_terminated) {
+            Ok(params) => overrides.extend(params),
+            Err(err) => result = Err(err),
+        }
+        false
+    });
+    result?;
+
+    let mut used = UsedNames::default();
+    for field in &item.fields {
+        used.visit_type(&field.ty);
+    }
+
+    for (i, param) in overrides.iter().enumerate() {
+        let name = param.name.to_string();
+        if !item.generics.params.iter().any(|p| generic_name(p) == name) {
+            return Err(Error::new_spanned(
+                &param.name,
+                format!("`{}` is not a generic parameter of `{}`", name, item.ident),
+            ));
+        }
+        if used.0.contains(&name) {
+            return Err(Error::new_spanned(
+                &param.name,
+                format!(
+                    "`{}` is used by a field of `{}`, so its variance cannot be overridden",
+                    name, item.ident,
+                ),
+            ));
+        }
+        if overrides[..i].iter().any(|p| p.name.to_string() == name) {
+            return Err(Error::new_spanned(
+                &param.name,
+                format!("`{}` is listed more than once", name),
+            ));
+        }
+    }
+
+    let unused = item
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| {
+            let ty = match param {
+                GenericParam::Lifetime(param) => {
+                    let lifetime = &param.lifetime;
+                    quote!(&#lifetime ())
+                }
+                GenericParam::Type(param) => param.ident.to_token_stream(),
+                GenericParam::Const(_) => return None,
+            };
+            let name = generic_name(param);
+            if used.0.contains(&name) {
+                return None;
+            }
+            let variance = overrides
+                .iter()
+                .find(|p| p.name.to_string() == name)
+                .map_or(&Variance::Invariant, |p| &p.variance);
+            Some((ty, variance))
+        })
+        .collect::<Vec<_>>();
+    if unused.is_empty() {
+        return Err(Error::new_spanned(
+            &item.ident,
+            format!("`{}` has no unused generic parameters", item.ident),
+        ));
+    }
+
+    push_field(item, unused_type(unused.into_iter()), "unused_field")
+}
+
+/// Returns the name of a generic parameter as it would be written in a type,
+/// such as `T` or `'a`.
+fn generic_name(param: &GenericParam) -> String {
+    match param {
+        GenericParam::Lifetime(param) => param.lifetime.to_string(),
+        GenericParam::Type(param) => param.ident.to_string(),
+        GenericParam::Const(param) => param.ident.to_string(),
+    }
+}
+
+/// Collects every name in a type that could refer to a generic parameter.
+#[derive(Default)]
+struct UsedNames(HashSet<String>);
+
+impl UsedNames {
+    fn visit_tokens(&mut self, tokens: TokenStream2) {
+        let mut lifetime = false;
+        for token in tokens {
+            match &token {
+                TokenTree::Ident(ident) if lifetime => {
+                    self.0.insert(format!("'{}", ident));
+                }
+                TokenTree::Ident(ident) => {
+                    self.0.insert(ident.to_string());
+                }
+                TokenTree::Group(group) => self.visit_tokens(group.stream()),
+                _ => {}
+            }
+            lifetime = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '\'');
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for UsedNames {
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.0.insert(lifetime.to_string());
+    }
+
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        if path.leading_colon.is_none() {
+            if let Some(segment) = path.segments.first() {
+                self.0.insert(segment.ident.to_string());
+            }
+        }
+        syn::visit::visit_path(self, path);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        self.visit_tokens(mac.tokens.clone());
+    }
+}
+
+/// Adds a field named `FIELD_NAME` with the type `ty` to a struct.
+fn push_field(item: &mut ItemStruct, ty: TokenStream2, attr: &str) -> Result<()> {
+    match &mut item.fields {
+        Fields::Named(fields) => {
+            if fields
+                .named
+                .iter()
+                .any(|field| matches!(&field.ident, Some(ident) if ident == FIELD_NAME))
+            {
+                return Err(Error::new_spanned(
+                    &item.ident,
+                    format!(
+                        "`{}` already has a field named `{}`",
+                        item.ident, FIELD_NAME
+                    ),
+                ));
+            }
+            let name = Ident::new(FIELD_NAME, proc_macro2::Span::call_site());
+            fields
+                .named
+                .push(Field::parse_named.parse2(quote!(#name: #ty))?);
+        }
+        Fields::Unnamed(fields) => {
+            fields.unnamed.push(Field::parse_unnamed.parse2(ty)?);
+        }
+        Fields::Unit => {
+            return Err(Error::new_spanned(
+                &item.ident,
+                format!("`{}` cannot be used on unit structs", attr),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Builds the `Unused` type without using the `Unused!` macro.
+fn unused_type<'a>(
+    params: impl DoubleEndedIterator<Item = (TokenStream2, &'a Variance)>,
+) -> TokenStream2 {
+    let inner = params.rev().fold(
+        quote!(::unused::End),
+        |next, (ty, variance)| match variance {
+            Variance::Invariant => quote!(::unused::Invariant<#ty, #next>),
+            Variance::Covariant => quote!(::unused::Covariant<#ty, #next>),
+            Variance::Contravariant => quote!(::unused::Contravariant<#ty, #next>),
+        },
+    );
+    quote!(::unused::Unused<#inner>)
+}
+
+struct Param {
+    ident: Ident,
+    variance: Variance,
+}
+
+impl Parse for Param {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident = input.parse()?;
+        let variance = if input.parse::<Option<Token![:]>>()?.is_some() {
+            input.parse()?
+        } else {
+            Variance::Invariant
+        };
+        Ok(Self { ident, variance })
+    }
+}
+
+/// A variance override in `#[unused_variance(...)]`.
+struct Override {
+    name: ParamName,
+    variance: Variance,
+}
+
+impl Parse for Override {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let variance = input.parse()?;
+        Ok(Self { name, variance })
+    }
+}
+
+enum ParamName {
+    Lifetime(Lifetime),
+    Type(Ident),
+}
+
+impl Parse for ParamName {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Lifetime) {
+            input.parse().map(Self::Lifetime)
+        } else {
+            input.parse().map(Self::Type)
+        }
+    }
+}
+
+impl ToTokens for ParamName {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Self::Lifetime(lifetime) => lifetime.to_tokens(tokens),
+            Self::Type(ident) => ident.to_tokens(tokens),
+        }
+    }
+}
+
+impl fmt::Display for ParamName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lifetime(lifetime) => lifetime.fmt(f),
+            Self::Type(ident) => ident.fmt(f),
+        }
+    }
+}
+
+enum Variance {
+    Invariant,
+    Covariant,
+    Contravariant,
+}
+
+impl Parse for Variance {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "invariant" => Ok(Self::Invariant),
+            "covariant" => Ok(Self::Covariant),
+            "contravariant" => Ok(Self::Contravariant),
+            _ => Err(Error::new(
+                ident.span(),
+                format!(
+                    "unknown variance `{}`, expected one of `invariant`, `covariant`, or \
+                     `contravariant`",
+                    ident,
+                ),
+            )),
+        }
+    }
+}
+
+const DEBUG_SKIP_ATTR: &str = "unused_debug_skip";
+
+/// Generates the `Debug` impl for `UnusedDebug`.
+fn debug_impl(item: &DeriveInput) -> Result<TokenStream2> {
+    let fields = match &item.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &item.ident,
+                "`UnusedDebug` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut used = UsedNames::default();
+    let mut calls = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let mut skip = false;
+        for attr in &field.attrs {
+            if attr.path().is_ident(DEBUG_SKIP_ATTR) {
+                attr.meta.require_path_only()?;
+                skip = true;
+            }
+        }
+        if skip {
+            continue;
+        }
+
+        if !is_unused(&field.ty) {
+            used.visit_type(&field.ty);
+        }
+        calls.push(match &field.ident {
+            Some(ident) => {
+                let name = ident.unraw().to_string();
+                quote!(.field(#name, &self.#ident))
+            }
+            None => {
+                let index = Index::from(i);
+                quote!(.field(&self.#index))
+            }
+        });
+    }
+
+    let mut generics = item.generics.clone();
+    let bounded: Vec<Ident> = generics
+        .type_params()
+        .filter(|param| used.0.contains(&param.ident.to_string()))
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = generics.make_where_clause();
+    for ident in bounded {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ident: ::core::fmt::Debug));
+    }
+
+    let ident = &item.ident;
+    let name = ident.to_string();
+    let body = match fields {
+        Fields::Named(_) => quote!(f.debug_struct(#name) #(#calls)* .finish()),
+        Fields::Unnamed(_) => quote!(f.debug_tuple(#name) #(#calls)* .finish()),
+        Fields::Unit => quote!(f.write_str(#name)),
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns whether a type is an `Unused`, written with the `Unused!` macro or
+/// as a path ending in `Unused`.
+fn is_unused(ty: &Type) -> bool {
+    let path = match ty {
+        Type::Macro(ty) => &ty.mac.path,
+        Type::Path(ty) if ty.qself.is_none() => &ty.path,
+        Type::Group(ty) => return is_unused(&ty.elem),
+        Type::Paren(ty) => return is_unused(&ty.elem),
+        _ => return false,
+    };
+    matches!(path.segments.last(), Some(segment) if segment.ident == "Unused")
+}
@@ -0,0 +1,4 @@
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+pub fn auto_traits_are_implemented<T: ?Sized + Send + Sync + Unpin + UnwindSafe + RefUnwindSafe>() {
+}
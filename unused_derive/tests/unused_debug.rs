@@ -0,0 +1,136 @@
+use std::rc::Rc;
+
+use unused::Unused;
+use unused_derive::{unused_params, UnusedDebug};
+
+#[derive(UnusedDebug)]
+struct MyStruct {
+    value: u32,
+    #[unused_debug_skip]
+    _unused: Unused!(u8),
+}
+
+#[derive(UnusedDebug)]
+struct Generic<'a, T, U: ?Sized>
+where
+    T: Copy,
+{
+    value: &'a [T],
+    #[unused_debug_skip]
+    unused: Unused!(U: covariant),
+    r#type: &'static str,
+}
+
+#[derive(UnusedDebug)]
+struct Tuple<T>(
+    #[unused_debug_skip] Unused!(T),
+    u8,
+    #[unused_debug_skip] Rc<()>,
+);
+
+#[derive(UnusedDebug)]
+struct Unit;
+
+#[unused_params(T)]
+#[derive(UnusedDebug)]
+struct WithParams<T> {
+    value: u8,
+}
+
+#[test]
+fn skipped_fields_are_left_out() {
+    let my_struct = MyStruct {
+        value: 42,
+        _unused: <Unused!(u8)>::default(),
+    };
+    let debug = format!("{:?}", my_struct);
+    assert!(debug.contains("42"));
+    assert!(!debug.contains("_unused"));
+    assert_eq!(debug, "MyStruct { value: 42 }");
+    assert_eq!(format!("{:#?}", my_struct), "MyStruct {\n    value: 42,\n}");
+
+    let generic: Generic<'_, u8, str> = Generic {
+        value: &[1, 2],
+        unused: Unused,
+        r#type: "bytes",
+    };
+    assert_eq!(
+        format!("{:?}", generic),
+        r#"Generic { value: [1, 2], type: "bytes" }"#
+    );
+    assert_eq!(generic.unused, Unused);
+
+    let tuple: Tuple<Rc<()>> = Tuple(Unused, 3, Rc::new(()));
+    assert_eq!(format!("{:?}", tuple), "Tuple(3)");
+    assert_eq!(Rc::strong_count(&tuple.2), 1);
+    assert_eq!(format!("{:?}", Unit), "Unit");
+}
+
+#[test]
+fn generic_parameters_need_not_be_debug() {
+    struct NotDebug;
+
+    let generic: Generic<'_, u8, NotDebug> = Generic {
+        value: &[],
+        unused: Unused,
+        r#type: "empty",
+    };
+    assert_eq!(
+        format!("{:?}", generic),
+        r#"Generic { value: [], type: "empty" }"#
+    );
+
+    // Fields that are not skipped are still formatted, including the
+    // `Unused` added by `unused_params`.
+    let with_params: WithParams<NotDebug> = WithParams {
+        value: 1,
+        __unused: Unused,
+    };
+    assert_eq!(
+        format!("{:?}", with_params),
+        "WithParams { value: 1, __unused: Unused }"
+    );
+}
+
+#[derive(UnusedDebug)]
+struct List {
+    value: u8,
+    next: Option<Box<List>>,
+}
+
+#[derive(UnusedDebug)]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+    #[unused_debug_skip]
+    _unused: Unused!(T),
+}
+
+#[test]
+fn recursive_types() {
+    let list = List {
+        value: 1,
+        next: Some(Box::new(List {
+            value: 2,
+            next: None,
+        })),
+    };
+    assert_eq!(
+        format!("{:?}", list),
+        "List { value: 1, next: Some(List { value: 2, next: None }) }"
+    );
+
+    let tree = Tree {
+        value: 'a',
+        children: vec![Tree {
+            value: 'b',
+            children: Vec::new(),
+            _unused: Unused,
+        }],
+        _unused: Unused,
+    };
+    assert_eq!(
+        format!("{:?}", tree),
+        "Tree { value: 'a', children: [Tree { value: 'b', children: [] }] }"
+    );
+}
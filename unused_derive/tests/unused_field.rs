@@ -0,0 +1,81 @@
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use unused::{Contravariant, Covariant, Invariant, Unused};
+use unused_derive::unused_field;
+
+mod common;
+
+use common::auto_traits_are_implemented;
+
+#[unused_field]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+struct Named<T, U> {
+    value: T,
+}
+
+#[unused_field]
+#[unused_variance('b: covariant, V: contravariant)]
+#[derive(Debug, Clone, Copy)]
+struct Mixed<'a, 'b, U, V, const N: usize> {
+    value: &'a [U; N],
+}
+
+#[unused_field]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+struct Tuple<T, U>(PhantomData<T>, u8);
+
+macro_rules! ty {
+    ($t:ty) => {
+        Option<$t>
+    };
+}
+
+#[unused_field]
+struct InMacro<T, U> {
+    value: ty!(T),
+}
+
+#[test]
+fn field_is_added() {
+    let named: Named<u8, String> = Named {
+        value: 1,
+        __unused: Unused,
+    };
+    assert_eq!(named.clone(), named);
+    let _field: Unused<Invariant<String, unused::End>> = named.__unused;
+
+    let tuple: Tuple<u8, String> = Tuple(PhantomData, 0, Unused);
+    assert_eq!(tuple, Tuple::default());
+    let _field: Unused<Invariant<String, unused::End>> = tuple.2;
+
+    let mixed: Mixed<'_, 'static, u8, u16, 2> = Mixed {
+        value: &[1, 2],
+        __unused: Unused,
+    };
+    assert_eq!(mixed.value, &[1, 2]);
+    let _field: Unused<Covariant<&'static (), Contravariant<u16, unused::End>>> = mixed.__unused;
+
+    let in_macro: InMacro<u8, u16> = InMacro {
+        value: None,
+        __unused: Unused,
+    };
+    assert_eq!(in_macro.value, None);
+    let _field: Unused<Invariant<u16, unused::End>> = in_macro.__unused;
+}
+
+#[test]
+fn auto_traits() {
+    auto_traits_are_implemented::<Named<u8, Rc<Cell<u8>>>>();
+    auto_traits_are_implemented::<Mixed<'static, 'static, u8, *const u8, 1>>();
+}
+
+#[test]
+fn variance() {
+    fn _covariant<'a>(
+        mixed: Mixed<'a, 'static, u8, &'a str, 1>,
+    ) -> Mixed<'a, 'a, u8, &'static str, 1> {
+        mixed
+    }
+}
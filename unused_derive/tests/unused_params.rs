@@ -0,0 +1,51 @@
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use unused::Unused;
+use unused_derive::unused_params;
+
+mod common;
+
+use common::auto_traits_are_implemented;
+
+#[unused_params(T, U: covariant, V: contravariant)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+struct Named<T, U, V> {
+    value: u8,
+}
+
+#[unused_params(T)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+struct Tuple<T>(u8);
+
+#[test]
+fn field_is_added() {
+    let named: Named<u8, u16, u32> = Named {
+        value: 0,
+        __unused: Unused,
+    };
+    assert_eq!(named, Named::default().clone());
+    assert_ne!(named, Named { value: 2, ..named });
+
+    let tuple: Tuple<u8> = Tuple(1, Unused);
+    assert_eq!(tuple.1, Unused);
+    assert_eq!(format!("{:?}", tuple), "Tuple(1, Unused)");
+
+    let mut hasher = DefaultHasher::new();
+    tuple.hash(&mut hasher);
+}
+
+#[test]
+fn auto_traits() {
+    auto_traits_are_implemented::<Named<Rc<str>, Cell<u8>, *const u8>>();
+    auto_traits_are_implemented::<Tuple<Rc<str>>>();
+}
+
+#[test]
+fn variance() {
+    fn _covariant<'a>(named: Named<u8, &'static str, &'a str>) -> Named<u8, &'a str, &'static str> {
+        named
+    }
+}